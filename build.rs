@@ -0,0 +1,187 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+// Turns every OBJ file in content/ into a built-in `Shape` backed by constant arrays, see shape.rs
+fn main() {
+    println!("cargo:rerun-if-changed=content");
+    // Hand-written shapes decide which files get skipped, so adding or removing one has to regenerate too
+    println!("cargo:rerun-if-changed=src/shape");
+
+    let mut generated = String::new();
+    let mut paths: Vec<_> = fs::read_dir("content")
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+
+    paths.sort();
+
+    for path in paths {
+        if path.extension().and_then(|e| e.to_str()) != Some("obj") {
+            continue;
+        }
+
+        let stem = path.file_stem().unwrap().to_string_lossy();
+        let module = module_name(&stem);
+
+        // A hand-written shape with the same name wins
+        if Path::new("src/shape").join(format!("{module}.rs")).exists() {
+            println!(
+                "cargo:warning=skipping {}, src/shape/{module}.rs already exists",
+                path.display()
+            );
+            continue;
+        }
+
+        let source =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+        let (vertices, indices) = parse(&source, &path);
+
+        generated.push_str(&shape(&module, &vertices, &indices));
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("shapes.rs");
+
+    fs::write(out, generated).expect("Could not write shapes.rs");
+}
+
+// Only positions and connectivity are needed, faces keep their polygons and polylines are split into segments
+fn parse(source: &str, path: &Path) -> (Vec<[f32; 3]>, Vec<Vec<usize>>) {
+    let mut vertices: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<Vec<usize>> = Vec::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let fail = |message: &str| -> ! { panic!("{}:{}: {message}", path.display(), number + 1) };
+        let content = line.split('#').next().unwrap_or_default();
+        let mut tokens = content.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let values: Vec<f32> = tokens
+                    .take(3)
+                    .map(|t| t.parse().unwrap_or_else(|_| fail("invalid number")))
+                    .collect();
+
+                if values.len() < 3 {
+                    fail("vertex needs x, y and z");
+                }
+
+                vertices.push([values[0], values[1], values[2]]);
+            }
+            Some(kind @ ("f" | "l")) => {
+                let element: Vec<usize> = tokens
+                    .map(|t| {
+                        let index: i64 = t
+                            .split('/')
+                            .next()
+                            .and_then(|i| i.parse().ok())
+                            .unwrap_or_else(|| fail("invalid index"));
+                        let resolved = match index {
+                            1.. => index - 1,
+                            _ => vertices.len() as i64 + index,
+                        };
+
+                        if resolved < 0 || resolved >= vertices.len() as i64 {
+                            fail("index is out of range");
+                        }
+
+                        resolved as usize
+                    })
+                    .collect();
+
+                match kind {
+                    "f" => indices.push(element),
+                    _ => indices.extend(element.windows(2).map(|w| w.to_vec())),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (vertices, indices)
+}
+
+// The data lives in `Name::VERTICES` and `Name::INDICES`, the `Shape` impl only copies it out when a mesh is built
+fn shape(module: &str, vertices: &[[f32; 3]], indices: &[Vec<usize>]) -> String {
+    let name = struct_name(module);
+    let mut code = String::new();
+
+    writeln!(code, "pub mod {module} {{").unwrap();
+    writeln!(code, "    use crate::point3d::Point3D;\n").unwrap();
+    writeln!(code, "    pub struct {name};\n").unwrap();
+    writeln!(code, "    impl {name} {{").unwrap();
+    writeln!(code, "        pub const VERTICES: &'static [Point3D] = &[").unwrap();
+
+    for [x, y, z] in vertices {
+        writeln!(
+            code,
+            "            Point3D {{ x: {x:?}, y: {y:?}, z: {z:?} }},"
+        )
+        .unwrap();
+    }
+
+    writeln!(code, "        ];\n").unwrap();
+    writeln!(
+        code,
+        "        pub const INDICES: &'static [&'static [usize]] = &["
+    )
+    .unwrap();
+
+    for element in indices {
+        writeln!(code, "            &{element:?},").unwrap();
+    }
+
+    writeln!(code, "        ];").unwrap();
+    writeln!(code, "    }}\n").unwrap();
+    writeln!(code, "    impl super::Shape for {name} {{").unwrap();
+    writeln!(
+        code,
+        "        fn name() -> &'static str {{\n            {module:?}\n        }}\n"
+    )
+    .unwrap();
+    writeln!(code, "        fn vertices() -> Vec<Point3D> {{").unwrap();
+    writeln!(code, "            Self::VERTICES.to_vec()").unwrap();
+    writeln!(code, "        }}\n").unwrap();
+    writeln!(code, "        fn indices() -> Vec<Vec<usize>> {{").unwrap();
+    writeln!(
+        code,
+        "            Self::INDICES.iter().map(|i| i.to_vec()).collect()"
+    )
+    .unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}\n").unwrap();
+
+    code
+}
+
+// "my model-v2" -> my_model_v2
+fn module_name(stem: &str) -> String {
+    let mut name: String = stem
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+// my_model_v2 -> MyModelV2
+fn struct_name(module: &str) -> String {
+    let name: String = module
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| part[..1].to_uppercase() + &part[1..])
+        .collect();
+
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("Shape{name}"),
+        false => name,
+    }
+}
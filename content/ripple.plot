@@ -0,0 +1,5 @@
+# cargo run -- --config content/ripple.plot
+formula = sin(sqrt(x^2 + y^2) * 2 - t * 3) / (1 + sqrt(x^2 + y^2))
+x = -6 6
+y = -6 6
+steps = 48
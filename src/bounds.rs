@@ -0,0 +1,80 @@
+use crate::point3d::Point3D;
+
+// Axis-aligned bounding box
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Bounds {
+    pub min: Point3D,
+    pub max: Point3D,
+}
+
+impl Bounds {
+    pub fn new(min: Point3D, max: Point3D) -> Self {
+        Self { min, max }
+    }
+
+    // None when there are no points to bound
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Point3D>,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+
+        Some(points.fold(Self::new(first, first), |bounds, p| {
+            Self::new(bounds.min.min(p), bounds.max.max(p))
+        }))
+    }
+
+    pub fn center(&self) -> Point3D {
+        self.min.lerp(&self.max, 0.5)
+    }
+
+    pub fn size(&self) -> Point3D {
+        self.max - self.min
+    }
+
+    // Length of the longest side
+    pub fn extent(&self) -> f32 {
+        let size = self.size();
+
+        size.x.max(size.y).max(size.z)
+    }
+
+    pub fn contains(&self, p: &Point3D) -> bool {
+        self.min.x <= p.x
+            && p.x <= self.max.x
+            && self.min.y <= p.y
+            && p.y <= self.max.y
+            && self.min.z <= p.z
+            && p.z <= self.max.z
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere {
+    pub center: Point3D,
+    pub radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Point3D, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    // Smallest sphere around a fixed center that holds every point, not the minimal bounding sphere but never misses a point
+    pub fn around<'a, I>(center: Point3D, points: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Point3D>,
+    {
+        let radius = points
+            .into_iter()
+            .map(|p| center.distance(p))
+            .fold(0.0, f32::max);
+
+        Self::new(center, radius)
+    }
+
+    pub fn contains(&self, p: &Point3D) -> bool {
+        self.center.distance(p) <= self.radius
+    }
+}
@@ -0,0 +1,234 @@
+use crate::bounds::Sphere;
+use crate::constants::{CANVAS_HEIGHT, CANVAS_WIDTH};
+use crate::mat4::Mat4;
+use crate::point3d::Point3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projection {
+    Perspective,
+    // Parallel projection showing `height` world units vertically, the width follows the aspect ratio
+    Orthographic { height: f32 },
+    // Parallel projection looking at the target from `pitch` degrees above the horizon and `yaw` degrees around it
+    Axonometric { pitch: f32, yaw: f32, height: f32 },
+    // Front view with depth receding at `angle` degrees from the horizontal, scaled by `depth`
+    Oblique { angle: f32, depth: f32, height: f32 },
+}
+
+impl Projection {
+    // All three axes equally foreshortened
+    pub fn isometric(height: f32) -> Self {
+        Self::Axonometric {
+            pitch: f32::atan(1.0 / f32::sqrt(2.0)).to_degrees(),
+            yaw: 45.0,
+            height,
+        }
+    }
+
+    // Engineering dimetric, x and y at full scale and z at half scale
+    pub fn dimetric(height: f32) -> Self {
+        Self::Axonometric {
+            pitch: f32::asin(1.0 / 3.0).to_degrees(),
+            yaw: f32::asin(f32::sqrt(1.0 / 8.0)).to_degrees(),
+            height,
+        }
+    }
+
+    // Every axis foreshortened differently, the angles are up to the caller
+    pub fn trimetric(pitch: f32, yaw: f32, height: f32) -> Self {
+        Self::Axonometric { pitch, yaw, height }
+    }
+
+    // Oblique with depth at full scale
+    pub fn cavalier(height: f32) -> Self {
+        Self::Oblique {
+            angle: 45.0,
+            depth: 1.0,
+            height,
+        }
+    }
+
+    // Oblique with depth at half scale, which reads closer to how the object actually looks
+    pub fn cabinet(height: f32) -> Self {
+        Self::Oblique {
+            angle: 45.0,
+            depth: 0.5,
+            height,
+        }
+    }
+
+    // Same projection showing `height` world units instead, perspective has no height so it's unchanged
+    pub fn with_height(self, height: f32) -> Self {
+        match self {
+            Self::Perspective => self,
+            Self::Orthographic { .. } => Self::Orthographic { height },
+            Self::Axonometric { pitch, yaw, .. } => Self::Axonometric { pitch, yaw, height },
+            Self::Oblique { angle, depth, .. } => Self::Oblique {
+                angle,
+                depth,
+                height,
+            },
+        }
+    }
+}
+
+// Left-handed camera that looks down +z by default, x points right and y points up
+pub struct Camera {
+    pub eye: Point3D,
+    pub target: Point3D,
+    pub up: Point3D,
+    pub fov: f32, // Vertical field of view in degrees
+    pub near: f32,
+    pub far: f32,
+    pub aspect: f32,
+    pub projection: Projection,
+}
+
+impl Camera {
+    pub fn new(eye: Point3D, target: Point3D) -> Self {
+        Self {
+            eye,
+            target,
+            up: Point3D::new(0.0, 1.0, 0.0),
+            fov: 90.0,
+            near: 0.1,
+            far: 100.0,
+            aspect: CANVAS_WIDTH / CANVAS_HEIGHT,
+            projection: Projection::Perspective,
+        }
+    }
+
+    // World space to camera space, the camera sits at the origin looking down +z
+    pub fn view(&self) -> Mat4 {
+        match self.projection {
+            Projection::Axonometric { pitch, yaw, .. } => {
+                look_at(&self.orbit(pitch, yaw), &self.target, &self.up)
+            }
+            Projection::Oblique { .. } => look_at(&self.orbit(0.0, 0.0), &self.target, &self.up),
+            _ => look_at(&self.eye, &self.target, &self.up),
+        }
+    }
+
+    // Camera space to clip space, after the divide by w the visible volume is [-1, 1] on every axis
+    pub fn projection(&self) -> Mat4 {
+        let depth = self.far - self.near;
+
+        match self.projection {
+            Projection::Perspective => {
+                let f = 1.0 / f32::tan(self.fov.to_radians() / 2.0);
+
+                Mat4::new([
+                    [f / self.aspect, 0.0, 0.0, 0.0],
+                    [0.0, f, 0.0, 0.0],
+                    [
+                        0.0,
+                        0.0,
+                        (self.far + self.near) / depth,
+                        -2.0 * self.far * self.near / depth,
+                    ],
+                    [0.0, 0.0, 1.0, 0.0],
+                ])
+            }
+            Projection::Orthographic { height } | Projection::Axonometric { height, .. } => {
+                orthographic(height, self.aspect, self.near, self.far)
+            }
+            Projection::Oblique {
+                angle,
+                depth: scale,
+                height,
+            } => {
+                // Shear around the target's depth so it stays centered while depth recedes diagonally
+                let distance = self.distance();
+                let dx = scale * f32::cos(angle.to_radians());
+                let dy = scale * f32::sin(angle.to_radians());
+
+                let shear = Mat4::new([
+                    [1.0, 0.0, dx, -dx * distance],
+                    [0.0, 1.0, dy, -dy * distance],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ]);
+
+                orthographic(height, self.aspect, self.near, self.far) * shear
+            }
+        }
+    }
+
+    // Height of the orthographic view volume that shows the target at the same size as the perspective view does
+    pub fn matched_ortho_height(&self) -> f32 {
+        2.0 * self.distance() * f32::tan(self.fov.to_radians() / 2.0)
+    }
+
+    pub fn toggle_projection(&mut self) {
+        self.projection = match self.projection {
+            Projection::Perspective => Projection::Orthographic {
+                height: self.matched_ortho_height(),
+            },
+            _ => Projection::Perspective,
+        };
+    }
+
+    // Aims at the sphere's center from just far enough that all of it fits on screen, keeping the current view direction
+    pub fn frame(&mut self, sphere: &Sphere) {
+        let direction = match (self.target - self.eye).normalize() {
+            d if d.length() == 0.0 => Point3D::new(0.0, 0.0, 1.0),
+            d => d,
+        };
+
+        // Whichever of the vertical and horizontal fields of view is narrower decides the distance
+        let half_fov = self.fov.to_radians() / 2.0;
+        let half_fov = half_fov.min(f32::atan(f32::tan(half_fov) * self.aspect));
+        let distance = (sphere.radius / f32::sin(half_fov)).max(self.near + sphere.radius);
+
+        self.target = sphere.center;
+        self.eye = sphere.center - direction * distance;
+        self.far = self.far.max(distance + sphere.radius);
+        self.projection = self.projection.with_height(self.matched_ortho_height());
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.eye.distance(&self.target)
+    }
+
+    // Eye position at the current distance from the target, rotated `pitch` degrees up and `yaw` degrees around it
+    fn orbit(&self, pitch: f32, yaw: f32) -> Point3D {
+        let distance = self.distance();
+        let (sin_pitch, cos_pitch) = pitch.to_radians().sin_cos();
+        let (sin_yaw, cos_yaw) = yaw.to_radians().sin_cos();
+
+        Point3D::new(
+            self.target.x - distance * cos_pitch * sin_yaw,
+            self.target.y + distance * sin_pitch,
+            self.target.z - distance * cos_pitch * cos_yaw,
+        )
+    }
+
+    pub fn view_projection(&self) -> Mat4 {
+        self.projection() * self.view()
+    }
+}
+
+fn look_at(eye: &Point3D, target: &Point3D, up: &Point3D) -> Mat4 {
+    let forward = (*target - *eye).normalize();
+    let right = up.cross(&forward).normalize();
+    let up = forward.cross(&right);
+
+    Mat4::new([
+        [right.x, right.y, right.z, -right.dot(eye)],
+        [up.x, up.y, up.z, -up.dot(eye)],
+        [forward.x, forward.y, forward.z, -forward.dot(eye)],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn orthographic(height: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+    let half_height = height / 2.0;
+    let half_width = half_height * aspect;
+    let depth = far - near;
+
+    Mat4::new([
+        [1.0 / half_width, 0.0, 0.0, 0.0],
+        [0.0, 1.0 / half_height, 0.0, 0.0],
+        [0.0, 0.0, 2.0 / depth, -(far + near) / depth],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
@@ -0,0 +1,50 @@
+use crate::point4d::Point4D;
+
+// Distance functions for the clip space planes, a point is inside a plane when its distance is >= 0
+const NEAR: fn(&Point4D) -> f32 = |p| p.z + p.w;
+const FAR: fn(&Point4D) -> f32 = |p| p.w - p.z;
+const LEFT: fn(&Point4D) -> f32 = |p| p.x + p.w;
+const RIGHT: fn(&Point4D) -> f32 = |p| p.w - p.x;
+const BOTTOM: fn(&Point4D) -> f32 = |p| p.y + p.w;
+const TOP: fn(&Point4D) -> f32 = |p| p.w - p.y;
+
+// Clips a segment against the near plane so edges crossing behind the camera keep only their visible part
+pub fn clip_near(a: &Point4D, b: &Point4D) -> Option<(Point4D, Point4D)> {
+    clip_planes(a, b, &[NEAR])
+}
+
+// Clips a segment against all six planes of the view frustum
+pub fn clip_frustum(a: &Point4D, b: &Point4D) -> Option<(Point4D, Point4D)> {
+    clip_planes(a, b, &[NEAR, FAR, LEFT, RIGHT, BOTTOM, TOP])
+}
+
+// Liang-Barsky style clipping, shrinks the parametric range [t0, t1] of the segment one plane at a time
+fn clip_planes(
+    a: &Point4D,
+    b: &Point4D,
+    planes: &[fn(&Point4D) -> f32],
+) -> Option<(Point4D, Point4D)> {
+    let mut t0: f32 = 0.0;
+    let mut t1: f32 = 1.0;
+
+    for plane in planes {
+        let da = plane(a);
+        let db = plane(b);
+
+        if da < 0.0 && db < 0.0 {
+            return None;
+        }
+
+        if da < 0.0 {
+            t0 = t0.max(da / (da - db));
+        } else if db < 0.0 {
+            t1 = t1.min(da / (da - db));
+        }
+
+        if t0 > t1 {
+            return None;
+        }
+    }
+
+    Some((a.lerp(b, t0), a.lerp(b, t1)))
+}
@@ -4,3 +4,9 @@ pub const CANVAS_WIDTH: f32 = 800.0;
 pub const FPS: f32 = 60.0;
 pub const DELTA_TIME: f32 = 1.0 / FPS;
 pub const FRAME_TIME: u64 = 1_000_000_000 / FPS as u64;
+
+// Highest level the S key steps up to before wrapping back to the bare cage
+pub const MAX_SUBDIVISION: usize = 3;
+
+// Validation warnings printed per mesh before the rest are summarized
+pub const MAX_WARNINGS: usize = 10;
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::io;
+
+use crate::mesh::validate::ValidationReport;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    // Malformed input, `line` is 1-based (0 for binary formats where there are no lines)
+    Parse {
+        line: usize,
+        message: String,
+    },
+    Unsupported(String),
+    // The file parsed but the mesh in it can't be rendered, the report lists why
+    Invalid {
+        name: String,
+        report: ValidationReport,
+    },
+}
+
+impl Error {
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Parse { line: 0, message } => write!(f, "{message}"),
+            Error::Parse { line, message } => write!(f, "line {line}: {message}"),
+            Error::Unsupported(what) => write!(f, "unsupported: {what}"),
+            Error::Invalid { name, report } => {
+                let mut errors = report.errors();
+
+                write!(f, "{name} is invalid")?;
+
+                if let Some(first) = errors.next() {
+                    write!(f, ": {first}")?;
+                }
+
+                match errors.count() {
+                    0 => Ok(()),
+                    more => write!(f, " (and {more} more)"),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
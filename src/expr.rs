@@ -0,0 +1,501 @@
+use std::fmt;
+
+use crate::error::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Variable {
+    X,
+    Y,
+    T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Sinh,
+    Cosh,
+    Tanh,
+    Sqrt,
+    Abs,
+    Exp,
+    Ln,
+    Log10,
+    Log2,
+    Pow,
+    Min,
+    Max,
+    Floor,
+    Ceil,
+}
+
+impl Function {
+    fn lookup(name: &str) -> Option<Self> {
+        let function = match name {
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "tan" => Self::Tan,
+            "asin" => Self::Asin,
+            "acos" => Self::Acos,
+            "atan" => Self::Atan,
+            "atan2" => Self::Atan2,
+            "sinh" => Self::Sinh,
+            "cosh" => Self::Cosh,
+            "tanh" => Self::Tanh,
+            "sqrt" => Self::Sqrt,
+            "abs" => Self::Abs,
+            "exp" => Self::Exp,
+            "ln" => Self::Ln,
+            "log10" => Self::Log10,
+            "log2" => Self::Log2,
+            "pow" => Self::Pow,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            _ => return None,
+        };
+
+        Some(function)
+    }
+
+    fn arity(&self) -> usize {
+        match self {
+            Self::Atan2 | Self::Pow | Self::Min | Self::Max => 2,
+            _ => 1,
+        }
+    }
+
+    // Trig works in radians, unlike the rest of the renderer, since that's what formulas are written in
+    fn apply(&self, args: &[f32]) -> f32 {
+        let a = args[0];
+
+        match self {
+            Self::Sin => a.sin(),
+            Self::Cos => a.cos(),
+            Self::Tan => a.tan(),
+            Self::Asin => a.asin(),
+            Self::Acos => a.acos(),
+            Self::Atan => a.atan(),
+            Self::Atan2 => a.atan2(args[1]),
+            Self::Sinh => a.sinh(),
+            Self::Cosh => a.cosh(),
+            Self::Tanh => a.tanh(),
+            Self::Sqrt => a.sqrt(),
+            Self::Abs => a.abs(),
+            Self::Exp => a.exp(),
+            Self::Ln => a.ln(),
+            Self::Log10 => a.log10(),
+            Self::Log2 => a.log2(),
+            Self::Pow => a.powf(args[1]),
+            Self::Min => a.min(args[1]),
+            Self::Max => a.max(args[1]),
+            Self::Floor => a.floor(),
+            Self::Ceil => a.ceil(),
+        }
+    }
+}
+
+// Parsed formula over x, y and t, e.g. `sin(x * x + y * y - t) / 2`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(f32),
+    Variable(Variable),
+    Negate(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+impl Expr {
+    // Usual precedence, `^` binds tighter than unary minus and is right associative so `-x^2^3` is `-(x^(2^3))`
+    // Errors are reported against the 1-based column in the source
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        };
+        let expr = parser.expression()?;
+
+        match parser.peek() {
+            None => Ok(expr),
+            Some((token, column, _)) => Err(error(*column, format!("unexpected {token}"))),
+        }
+    }
+
+    pub fn eval(&self, x: f32, y: f32, t: f32) -> f32 {
+        match self {
+            Expr::Number(n) => *n,
+            Expr::Variable(Variable::X) => x,
+            Expr::Variable(Variable::Y) => y,
+            Expr::Variable(Variable::T) => t,
+            Expr::Negate(e) => -e.eval(x, y, t),
+            Expr::Binary(op, a, b) => {
+                let (a, b) = (a.eval(x, y, t), b.eval(x, y, t));
+
+                match op {
+                    Operator::Add => a + b,
+                    Operator::Subtract => a - b,
+                    Operator::Multiply => a * b,
+                    Operator::Divide => a / b,
+                    Operator::Remainder => a % b,
+                    Operator::Power => a.powf(b),
+                }
+            }
+            Expr::Call(function, args) => {
+                let args: Vec<f32> = args.iter().map(|a| a.eval(x, y, t)).collect();
+
+                function.apply(&args)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f32),
+    Identifier(String),
+    Operator(char),
+    Open,
+    Close,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "'{n}'"),
+            Token::Identifier(name) => write!(f, "'{name}'"),
+            Token::Operator(c) => write!(f, "'{c}'"),
+            Token::Open => write!(f, "'('"),
+            Token::Close => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+        }
+    }
+}
+
+fn error(column: usize, message: impl Into<String>) -> Error {
+    Error::parse(0, format!("column {column}: {}", message.into()))
+}
+
+// Each token with the 1-based columns of its first character and just past its last
+type Spanned = (Token, usize, usize);
+
+fn tokenize(source: &str) -> Result<Vec<Spanned>, Error> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let start = i;
+
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+
+            // Only treat `e` as an exponent when digits follow, otherwise `2e` would swallow the constant
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let sign = matches!(chars.get(i + 1), Some('+' | '-')) as usize;
+
+                if chars.get(i + 1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1 + sign;
+
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+
+            let text: String = chars[start..i].iter().collect();
+            let number = text
+                .parse()
+                .map_err(|_| error(column, format!("invalid number '{text}'")))?;
+
+            tokens.push((Token::Number(number), column, i + 1));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            tokens.push((
+                Token::Identifier(chars[start..i].iter().collect()),
+                column,
+                i + 1,
+            ));
+            continue;
+        }
+
+        let token = match c {
+            '+' | '-' | '*' | '/' | '%' | '^' => Token::Operator(c),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            _ => return Err(error(column, format!("unexpected '{c}'"))),
+        };
+
+        tokens.push((token, column, column + 1));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Spanned> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    // Column just past the last token, for errors about missing input
+    fn end(&self) -> usize {
+        self.tokens.last().map_or(1, |&(_, _, end)| end)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Error> {
+        match self.next() {
+            Some((token, _, _)) if token == expected => Ok(()),
+            Some((token, column, _)) => {
+                Err(error(column, format!("expected {expected}, found {token}")))
+            }
+            None => Err(error(self.end(), format!("expected {expected}"))),
+        }
+    }
+
+    fn operator(&mut self, operators: &[char]) -> Option<Operator> {
+        let &(Token::Operator(c), _, _) = self.peek()? else {
+            return None;
+        };
+
+        if !operators.contains(&c) {
+            return None;
+        }
+
+        let operator = match c {
+            '+' => Operator::Add,
+            '-' => Operator::Subtract,
+            '*' => Operator::Multiply,
+            '/' => Operator::Divide,
+            '%' => Operator::Remainder,
+            _ => Operator::Power,
+        };
+
+        self.position += 1;
+        Some(operator)
+    }
+
+    fn expression(&mut self) -> Result<Expr, Error> {
+        let mut expr = self.term()?;
+
+        while let Some(op) = self.operator(&['+', '-']) {
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.term()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr, Error> {
+        let mut expr = self.unary()?;
+
+        while let Some(op) = self.operator(&['*', '/', '%']) {
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.unary()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, Error> {
+        match self.operator(&['-', '+']) {
+            Some(Operator::Subtract) => Ok(Expr::Negate(Box::new(self.unary()?))),
+            Some(_) => self.unary(),
+            None => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Expr, Error> {
+        let base = self.primary()?;
+
+        match self.operator(&['^']) {
+            Some(op) => Ok(Expr::Binary(op, Box::new(base), Box::new(self.unary()?))),
+            None => Ok(base),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr, Error> {
+        let end = self.end();
+
+        match self.next() {
+            Some((Token::Number(n), _, _)) => Ok(Expr::Number(n)),
+            Some((Token::Open, _, _)) => {
+                let expr = self.expression()?;
+                self.expect(Token::Close)?;
+                Ok(expr)
+            }
+            Some((Token::Identifier(name), column, _)) => self.identifier(&name, column),
+            Some((token, column, _)) => Err(error(column, format!("unexpected {token}"))),
+            None => Err(error(end, "unexpected end of formula")),
+        }
+    }
+
+    fn identifier(&mut self, name: &str, column: usize) -> Result<Expr, Error> {
+        match name {
+            "x" => return Ok(Expr::Variable(Variable::X)),
+            "y" => return Ok(Expr::Variable(Variable::Y)),
+            "t" => return Ok(Expr::Variable(Variable::T)),
+            "pi" => return Ok(Expr::Number(std::f32::consts::PI)),
+            "e" => return Ok(Expr::Number(std::f32::consts::E)),
+            _ => {}
+        }
+
+        let function = Function::lookup(name)
+            .ok_or_else(|| error(column, format!("unknown name '{name}'")))?;
+
+        self.expect(Token::Open)?;
+
+        let mut args = vec![self.expression()?];
+
+        while let Some((Token::Comma, _, _)) = self.peek() {
+            self.position += 1;
+            args.push(self.expression()?);
+        }
+
+        self.expect(Token::Close)?;
+
+        if args.len() != function.arity() {
+            return Err(error(
+                column,
+                format!(
+                    "{name} takes {} argument(s) but got {}",
+                    function.arity(),
+                    args.len()
+                ),
+            ));
+        }
+
+        Ok(Expr::Call(function, args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str) -> f32 {
+        Expr::parse(source).unwrap().eval(2.0, 3.0, 0.5)
+    }
+
+    fn parse_error(source: &str) -> String {
+        match Expr::parse(source) {
+            Err(Error::Parse { message, .. }) => message,
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn follows_the_usual_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), 7.0);
+        assert_eq!(eval("(1 + 2) * 3"), 9.0);
+        assert_eq!(eval("10 - 4 - 3"), 3.0);
+        assert_eq!(eval("12 / 3 / 2"), 2.0);
+        assert_eq!(eval("7 % 4 * 2"), 6.0);
+        assert_eq!(eval("1 + 2 ^ 3 * 2"), 17.0);
+    }
+
+    #[test]
+    fn power_is_right_associative_and_binds_tighter_than_unary_minus() {
+        assert_eq!(eval("2 ^ 3 ^ 2"), 512.0);
+        assert_eq!(eval("-2 ^ 2"), -4.0);
+        assert_eq!(eval("(-2) ^ 2"), 4.0);
+        assert_eq!(eval("2 ^ -1"), 0.5);
+        assert_eq!(
+            Expr::parse("-x^2^3").unwrap(),
+            Expr::parse("-(x^(2^3))").unwrap()
+        );
+    }
+
+    #[test]
+    fn handles_repeated_unary_operators() {
+        assert_eq!(eval("--x"), 2.0);
+        assert_eq!(eval("+-x"), -2.0);
+        assert_eq!(eval("3 * -y"), -9.0);
+        assert_eq!(eval("x - -y"), 5.0);
+    }
+
+    #[test]
+    fn reads_variables_constants_and_numbers() {
+        assert_eq!(eval("x * 100 + y * 10 + t"), 230.5);
+        assert_eq!(eval("pi"), std::f32::consts::PI);
+        assert_eq!(eval("2*e"), 2.0 * std::f32::consts::E);
+        assert_eq!(eval("1.5e2 + 2E-1 + .5"), 150.7);
+    }
+
+    #[test]
+    fn calls_functions_with_their_arity() {
+        assert_eq!(eval("sqrt(x * 8)"), 4.0);
+        assert_eq!(eval("max(x, y) - min(x, y)"), 1.0);
+        assert_eq!(eval("pow(x, y)"), 8.0);
+        assert_eq!(eval("atan2(0, 1) + floor(t) + ceil(t)"), 1.0);
+        assert_eq!(eval("abs(-sin(0))"), 0.0);
+    }
+
+    #[test]
+    fn reports_errors_with_their_column() {
+        assert_eq!(parse_error(""), "column 1: unexpected end of formula");
+        assert_eq!(parse_error("x +"), "column 4: unexpected end of formula");
+        assert_eq!(parse_error("x $ y"), "column 3: unexpected '$'");
+        assert_eq!(parse_error("x y"), "column 3: unexpected 'y'");
+        assert_eq!(parse_error("(x + 1"), "column 7: expected ')'");
+        assert_eq!(parse_error("(x + 1.25"), "column 10: expected ')'");
+        assert_eq!(parse_error("sin(t * 10"), "column 11: expected ')'");
+        assert_eq!(parse_error("x + )"), "column 5: unexpected ')'");
+        assert_eq!(parse_error("2e"), "column 2: unexpected 'e'");
+        assert_eq!(parse_error("1..2"), "column 1: invalid number '1..2'");
+        assert_eq!(parse_error("z * 2"), "column 1: unknown name 'z'");
+        assert_eq!(parse_error("sin x"), "column 5: expected '(', found 'x'");
+        assert_eq!(
+            parse_error("1 + pow(x)"),
+            "column 5: pow takes 2 argument(s) but got 1"
+        );
+        assert_eq!(
+            parse_error("sin(x, y)"),
+            "column 1: sin takes 1 argument(s) but got 2"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::f32::consts::PI;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::expr::Expr;
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+use crate::shape::grid::Grid;
+
+// Plots z = f(x, y, t) over a grid, the renderer's y axis points up so the formula's z becomes the mesh's y
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plot {
+    pub expr: Expr,
+    pub x: Range<f32>,
+    pub y: Range<f32>,
+    pub steps: usize,
+}
+
+impl Plot {
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr,
+            x: -PI..PI,
+            y: -PI..PI,
+            steps: 40,
+        }
+    }
+
+    pub fn parse(formula: &str) -> Result<Self, Error> {
+        Ok(Self::new(Expr::parse(formula)?))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::read(&fs::read_to_string(path)?)
+    }
+
+    // `key = value` lines, `#` starts a comment
+    //
+    //     formula = sin(sqrt(x^2 + y^2) - t)
+    //     x = -6 6
+    //     y = -6 6
+    //     steps = 60
+    pub fn read(config: &str) -> Result<Self, Error> {
+        let mut formula = None;
+        let mut x = None;
+        let mut y = None;
+        let mut steps = None;
+
+        for (i, line) in config.lines().enumerate() {
+            let number = i + 1;
+            let line = line.split('#').next().unwrap_or_default().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::parse(number, "expected 'key = value'"))?;
+            let value = value.trim();
+
+            match key.trim() {
+                "formula" => {
+                    formula = Some(Expr::parse(value).map_err(|err| match err {
+                        Error::Parse { message, .. } => Error::parse(number, message),
+                        err => err,
+                    })?)
+                }
+                "x" => x = Some(range(number, value)?),
+                "y" => y = Some(range(number, value)?),
+                "steps" => {
+                    steps = Some(value.parse().map_err(|_| {
+                        Error::parse(number, format!("invalid step count '{value}'"))
+                    })?)
+                }
+                key => return Err(Error::parse(number, format!("unknown key '{key}'"))),
+            }
+        }
+
+        let formula = formula.ok_or_else(|| Error::parse(0, "missing 'formula'"))?;
+        let defaults = Self::new(formula);
+
+        Ok(Self {
+            x: x.unwrap_or(defaults.x.clone()),
+            y: y.unwrap_or(defaults.y.clone()),
+            steps: steps.unwrap_or(defaults.steps),
+            ..defaults
+        })
+    }
+
+    // The grid at time `t`, only the vertices change over time so the edges can be computed once
+    pub fn mesh(&self, t: f32) -> Mesh {
+        let grid = Grid {
+            width: self.x.end - self.x.start,
+            depth: self.y.end - self.y.start,
+            columns: self.steps,
+            rows: self.steps,
+        };
+
+        Mesh {
+            name: "formula".to_owned(),
+            vertices: self.sample(t),
+            ..grid.build()
+        }
+    }
+
+    // Same vertex order as shape::grid::Grid, results that aren't finite (poles, logs of negatives) are flattened to 0
+    pub fn sample(&self, t: f32) -> Vec<Point3D> {
+        let steps = self.steps.max(1);
+        let lerp = |range: &Range<f32>, i: usize| {
+            range.start + (range.end - range.start) * i as f32 / steps as f32
+        };
+
+        (0..=steps)
+            .flat_map(|row| {
+                (0..=steps).map(move |column| (lerp(&self.x, column), lerp(&self.y, row)))
+            })
+            .map(|(x, y)| {
+                let z = self.expr.eval(x, y, t);
+
+                Point3D::new(x, if z.is_finite() { z } else { 0.0 }, y)
+            })
+            .collect()
+    }
+}
+
+fn range(line: usize, value: &str) -> Result<Range<f32>, Error> {
+    let bounds: Vec<f32> = value
+        .split_whitespace()
+        .map(|v| v.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::parse(line, format!("invalid range '{value}'")))?;
+
+    match bounds.as_slice() {
+        &[start, end] if start < end => Ok(start..end),
+        _ => Err(Error::parse(
+            line,
+            format!("expected 'min max' but found '{value}'"),
+        )),
+    }
+}
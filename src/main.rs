@@ -1,21 +1,56 @@
 use core::f32;
 use flo_canvas::*;
 use flo_draw::*;
+use futures::executor;
 use rayon::iter::{IntoParallelRefIterator as _, ParallelIterator as _};
+use std::env;
+use std::process;
+use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
-use crate::constants::{CANVAS_HEIGHT, CANVAS_WIDTH};
+use crate::bounds::Sphere;
+use crate::camera::{Camera, Projection};
+use crate::error::Error;
+use crate::formula::Plot;
+use crate::mat4::Mat4;
+use crate::mesh::decimate::Target;
+use crate::mesh::subdivide::{Scheme, Subdivision};
+use crate::mesh::validate::ValidationReport;
+use crate::mesh::{Edge, Mesh};
 use crate::point2d::Point2D;
-use crate::shape::{Shape as _, cube::Cube, penger::Penger};
+use crate::point3d::Point3D;
+use crate::point4d::Point4D;
+use crate::quaternion::Quaternion;
+use crate::shape::cone::Cone;
+use crate::shape::cylinder::Cylinder;
+use crate::shape::grid::Grid;
+use crate::shape::icosphere::Icosphere;
+use crate::shape::torus::Torus;
+use crate::shape::uv_sphere::UvSphere;
+use crate::shape::{
+    Shape, archimedean, cube::Cube, isosurface, parametric, penger::Penger, platonic,
+};
+use crate::viewport::Viewport;
 
+pub mod bounds;
+pub mod camera;
+pub mod clip;
 pub mod color;
 pub mod constants;
+pub mod error;
+pub mod expr;
+pub mod formula;
+pub mod mat4;
+pub mod mesh;
 pub mod point2d;
 pub mod point3d;
+pub mod point4d;
+pub mod quaternion;
 pub mod shape;
+pub mod viewport;
 
-pub fn queue_points<'a, I>(points: I, layer_id: LayerId, gc: &mut Vec<Draw>)
+pub fn queue_points<I>(points: I, layer_id: LayerId, gc: &mut Vec<Draw>)
 where
     I: IntoIterator<Item = Point2D>,
 {
@@ -47,26 +82,188 @@ where
     gc.stroke();
 }
 
-pub fn clear_layer(layer: LayerId, gc: &mut Vec<Draw>) {
+// Each segment is stroked on its own since a path can only have one color
+pub fn queue_colored_lines<'a, I>(segments: I, layer_id: LayerId, gc: &mut Vec<Draw>)
+where
+    I: IntoIterator<Item = (&'a Point2D, &'a Point2D, Color)>,
+{
+    gc.layer(layer_id);
+    gc.line_width(2.0);
+
+    for (p1, p2, color) in segments {
+        gc.new_path();
+        gc.move_to(p1.x, p1.y);
+        gc.line_to(p2.x, p2.y);
+        gc.stroke_color(color);
+        gc.stroke();
+    }
+}
+
+// A mesh drawn at an offset from the origin, its edges and their colors are worked out once up front
+// A subdivided copy keeps the weights that rebuild its vertices from the cage's when those move
+pub struct Model {
+    pub mesh: Mesh,
+    pub edges: Vec<Edge>,
+    pub colors: Option<Vec<Color>>,
+    pub offset: Point3D,
+    pub subdivision: Option<Subdivision>,
+}
+
+impl Model {
+    pub fn new(mesh: Mesh, offset: Point3D) -> Self {
+        let edges = mesh.edges();
+        let colors = mesh.colors_for(&edges);
+
+        Self {
+            mesh,
+            edges,
+            colors,
+            offset,
+            subdivision: None,
+        }
+    }
+
+    pub fn subdivided(cage: &Mesh, scheme: Scheme, level: usize, offset: Point3D) -> Self {
+        let (mesh, subdivision) = Subdivision::new(cage, scheme, level);
+
+        Self {
+            subdivision: Some(subdivision),
+            ..Self::new(mesh, offset)
+        }
+    }
+
+    // Moves the vertices to follow the cage's, the topology stays as it was
+    pub fn follow(&mut self, cage: &Mesh) {
+        self.mesh.vertices = match &self.subdivision {
+            Some(subdivision) => subdivision.apply(&cage.vertices),
+            None => cage.vertices.clone(),
+        };
+    }
+}
+
+// The cage on its own, or on the left of its subdivided copy, along with a sphere that holds everything
+// Both spin around their own centers so the cage is expected to be centered on the origin (see Mesh::fit)
+pub fn arrange(cage: &Mesh, scheme: Scheme, level: usize) -> (Vec<Model>, Sphere) {
+    let sphere = cage.bounding_sphere().unwrap_or_default();
+
+    if level == 0 {
+        return (vec![Model::new(cage.clone(), Point3D::default())], sphere);
+    }
+
+    let spacing = Point3D::new(sphere.radius * 1.1, 0.0, 0.0);
+    let models = vec![
+        Model::new(cage.clone(), -spacing),
+        Model::subdivided(cage, scheme, level, spacing),
+    ];
+
+    (
+        models,
+        Sphere::new(sphere.center, spacing.x + sphere.radius),
+    )
+}
+
+// Shapes that can be picked with `--shape <name>`
+pub const BUILTINS: &[&str] = &[
+    "penger",
+    "cube",
+    "gyroid",
+    "metaballs",
+    "torus_sdf",
+    "uv_sphere",
+    "icosphere",
+    "torus",
+    "cylinder",
+    "cone",
+    "grid",
+    "mobius_strip",
+    "klein_bottle",
+    "torus_knot",
+    "tetrahedron",
+    "hexahedron",
+    "octahedron",
+    "dodecahedron",
+    "icosahedron",
+    "truncated_tetrahedron",
+    "cuboctahedron",
+    "truncated_cube",
+    "truncated_octahedron",
+    "rhombicuboctahedron",
+    "truncated_cuboctahedron",
+    "icosidodecahedron",
+    "truncated_dodecahedron",
+    "truncated_icosahedron",
+    "rhombicosidodecahedron",
+    "truncated_icosidodecahedron",
+];
+
+pub fn builtin(name: &str) -> Option<Mesh> {
+    let mesh = match name {
+        "penger" => Penger::mesh(),
+        "cube" => Cube::mesh(),
+        "gyroid" => isosurface::gyroid(),
+        "metaballs" => isosurface::metaballs(&[
+            (Point3D::new(-0.35, 0.0, 0.0), 0.3),
+            (Point3D::new(0.3, 0.15, 0.0), 0.25),
+            (Point3D::new(0.0, -0.3, 0.2), 0.2),
+        ]),
+        "torus_sdf" => isosurface::torus_sdf(0.5, 0.2),
+        "uv_sphere" => UvSphere::default().build(),
+        "icosphere" => Icosphere::default().build(),
+        "torus" => Torus::default().build(),
+        "cylinder" => Cylinder::default().build(),
+        "cone" => Cone::default().build(),
+        "grid" => Grid::default().build(),
+        "mobius_strip" => parametric::mobius_strip(),
+        "klein_bottle" => parametric::klein_bottle(),
+        "torus_knot" => parametric::torus_knot(2, 3),
+        "tetrahedron" => platonic::Tetrahedron::mesh(),
+        "hexahedron" => platonic::Hexahedron::mesh(),
+        "octahedron" => platonic::Octahedron::mesh(),
+        "dodecahedron" => platonic::Dodecahedron::mesh(),
+        "icosahedron" => platonic::Icosahedron::mesh(),
+        "truncated_tetrahedron" => archimedean::TruncatedTetrahedron::mesh(),
+        "cuboctahedron" => archimedean::Cuboctahedron::mesh(),
+        "truncated_cube" => archimedean::TruncatedCube::mesh(),
+        "truncated_octahedron" => archimedean::TruncatedOctahedron::mesh(),
+        "rhombicuboctahedron" => archimedean::Rhombicuboctahedron::mesh(),
+        "truncated_cuboctahedron" => archimedean::TruncatedCuboctahedron::mesh(),
+        "icosidodecahedron" => archimedean::Icosidodecahedron::mesh(),
+        "truncated_dodecahedron" => archimedean::TruncatedDodecahedron::mesh(),
+        "truncated_icosahedron" => archimedean::TruncatedIcosahedron::mesh(),
+        "rhombicosidodecahedron" => archimedean::Rhombicosidodecahedron::mesh(),
+        "truncated_icosidodecahedron" => archimedean::TruncatedIcosidodecahedron::mesh(),
+        _ => return None,
+    };
+
+    Some(mesh)
+}
+
+pub fn report_warnings(name: &str, report: &ValidationReport) {
+    for line in report.warning_summary(constants::MAX_WARNINGS) {
+        eprintln!("{name}: {line}");
+    }
+}
+
+pub fn clear_layer(layer: LayerId, gc: &mut Vec<Draw>, viewport: &Viewport) {
     gc.layer(layer);
     gc.clear_layer();
-    gc.canvas_height(CANVAS_HEIGHT);
+    gc.canvas_height(viewport.height);
 }
 
-pub fn set_boundary(canvas: &DrawingTarget) {
+pub fn set_boundary(canvas: &DrawingTarget, viewport: &Viewport) {
     let boundary = Color::Rgba(0.2, 0.2, 0.2, 1.0);
 
     canvas.draw(|gc| {
         gc.layer(LayerId(0));
         gc.clear_layer();
-        gc.canvas_height(CANVAS_HEIGHT);
+        gc.canvas_height(viewport.height);
 
         gc.new_path();
         gc.rect(
-            -(CANVAS_WIDTH / 2.0),
-            -(CANVAS_HEIGHT / 2.0),
-            CANVAS_WIDTH / 2.0,
-            CANVAS_HEIGHT / 2.0,
+            viewport.offset.x - viewport.width / 2.0,
+            viewport.offset.y - viewport.height / 2.0,
+            viewport.offset.x + viewport.width / 2.0,
+            viewport.offset.y + viewport.height / 2.0,
         );
         gc.fill_color(boundary);
         gc.fill();
@@ -75,46 +272,213 @@ pub fn set_boundary(canvas: &DrawingTarget) {
 
 // Inspired by Tsoding: https://github.com/tsoding/formula
 pub fn main() {
+    // Pass a model file (e.g. content/penger.obj) to view it, `--shape <name>` for one of the BUILTINS,
+    // `--formula "<expr>"` or `--config <file>` to plot z = f(x, y, t), otherwise fall back to the built-in Penger
+    let args: Vec<String> = env::args().skip(1).collect();
+    let plot = match args.as_slice() {
+        [flag, formula] if flag == "--formula" => Some(Plot::parse(formula)),
+        [flag, path] if flag == "--config" => Some(Plot::load(path)),
+        _ => None,
+    }
+    .transpose()
+    .unwrap_or_else(|err| {
+        eprintln!("Failed to read formula: {err}");
+        process::exit(1);
+    });
+
+    // Built-in meshes are validated like loaded ones so a bad index is reported here instead of panicking mid-render
+    let loaded = match (&plot, args.as_slice()) {
+        (Some(plot), _) => plot.mesh(0.0).checked().map(|checked| vec![checked]),
+        (None, [flag, name]) if flag == "--shape" => match builtin(name) {
+            Some(mesh) => mesh.checked().map(|checked| vec![checked]),
+            None => Err(Error::Unsupported(format!(
+                "no built-in shape '{name}', pick one of {}",
+                BUILTINS.join(", ")
+            ))),
+        },
+        (None, [path, ..]) => Mesh::load(path),
+        (None, []) => Penger::mesh().checked().map(|checked| vec![checked]),
+    }
+    .unwrap_or_else(|err| {
+        let source = args.last().map_or(Penger::name(), String::as_str);
+
+        eprintln!("Failed to load {source}: {err}");
+        process::exit(1);
+    });
+
+    for (mesh, report) in &loaded {
+        report_warnings(&mesh.name, report);
+    }
+
+    let mut meshes: Vec<Mesh> = loaded.into_iter().map(|(mesh, _)| mesh).collect();
+    let mesh = match (&plot, args.as_slice()) {
+        (None, [flag, _]) if flag == "--shape" => meshes.swap_remove(0),
+        (None, [path, ..]) => Mesh::merge(path, &meshes),
+        _ => meshes.swap_remove(0),
+    };
+    // Models come in all sizes and offsets, move them to the origin and size them to match so the camera can frame any of them
+    // Plots keep the transform from their first frame so the surface doesn't jump around as it animates
+    let fit = mesh.fit_transform(1.0);
+    let mut mesh = mesh.transform(&fit);
+
+    // Press S to step through the subdivision levels, Loop for triangle meshes and Catmull-Clark for everything else
+    let mut scheme = Scheme::for_mesh(&mesh);
+    let mut level = 0;
+    let (mut models, mut sphere) = arrange(&mesh, scheme, level);
+
     // 'with_2d_graphics' is used to support operating systems that can't run event loops anywhere other than the main thread
-    with_2d_graphics(|| {
+    with_2d_graphics(move || {
         // Create a window with a canvas to draw on
-        let canvas = create_drawing_window("Wireframe Renderer");
+        let (canvas, events) = create_drawing_window_with_events("Wireframe Renderer");
+
+        // Forward window events to the render loop, the stream is async so drain it on its own thread
+        let (sender, input) = mpsc::channel();
+
+        thread::spawn(move || {
+            for event in executor::block_on_stream(Box::pin(events)) {
+                if sender.send(event).is_err() {
+                    break;
+                }
+            }
+        });
 
         // Clear the canvas to set a background color
+        let mut viewport = Viewport::default();
+
         canvas.draw(|gc| gc.clear_canvas(Color::Rgba(0.0, 0.0, 0.0, 1.0)));
-        set_boundary(&canvas);
+        set_boundary(&canvas, &viewport);
 
-        // If you want to see Penger, replace "Cube" with "Penger" or add your own shape in the shape folder
-        let vs = Penger::vertices().expect("No vertices found.");
-        let fs = Penger::indices().expect("No indices found.");
+        // Frame the model from a distance instead of moving it away from the origin
+        let mut camera = Camera::new(Point3D::new(0.0, 0.0, -1.0), Point3D::new(0.0, 0.0, 0.0));
+        camera.frame(&sphere);
 
         // Animate them
-        let dz = 1.0;
-        let mut angle = 0.0;
+        // About -y so it spins the same way as the original rotate_y
+        let spin = Quaternion::from_axis_angle(
+            &Point3D::new(0.0, -1.0, 0.0),
+            50.0 * f32::consts::PI * constants::DELTA_TIME,
+        );
+        let mut orientation = Quaternion::identity();
+
+        // Views cycled with V, parallel ones are sized to match the perspective framing
+        let views: [fn(f32) -> Projection; 7] = [
+            |_| Projection::Perspective,
+            |height| Projection::Orthographic { height },
+            Projection::isometric,
+            Projection::dimetric,
+            |height| Projection::trimetric(25.0, 15.0, height),
+            Projection::cavalier,
+            Projection::cabinet,
+        ];
+        let mut view = 0;
+        let mut time = 0.0;
+
+        // Press F to clip against the whole frustum instead of only the near plane
+        let mut frustum = false;
 
         loop {
-            // dz += 1.0 * DELTA_TIME;
-            angle += 50.0 * f32::consts::PI * constants::DELTA_TIME;
+            // Press P to switch between perspective and orthographic projection, V to step through every view, S to subdivide, D to decimate, F to clip against the whole frustum or E to export the mesh
+            for event in input.try_iter() {
+                match event {
+                    DrawEvent::KeyDown(_, Some(Key::KeyP)) => {
+                        camera.toggle_projection();
+                        // P only ever lands on the first two views, keep V stepping on from whichever is on screen
+                        view = match camera.projection {
+                            Projection::Perspective => 0,
+                            _ => 1,
+                        };
+                    }
+                    DrawEvent::KeyDown(_, Some(Key::KeyV)) => {
+                        view = (view + 1) % views.len();
+                        camera.projection = views[view](camera.matched_ortho_height());
+                    }
+                    DrawEvent::KeyDown(_, Some(Key::KeyS)) => {
+                        level = (level + 1) % (constants::MAX_SUBDIVISION + 1);
+                        (models, sphere) = arrange(&mesh, scheme, level);
+                        camera.frame(&sphere);
+                        println!("Subdivision level {level}");
+                    }
+                    // Plots replace the vertices every frame so their topology has to stay as it is
+                    DrawEvent::KeyDown(_, Some(Key::KeyD)) if plot.is_none() => {
+                        let triangles: usize =
+                            mesh.faces.iter().map(|f| f.len().saturating_sub(2)).sum();
+
+                        mesh = mesh.decimate(Target::Faces(triangles / 2));
+                        scheme = Scheme::for_mesh(&mesh);
+                        (models, sphere) = arrange(&mesh, scheme, level);
+                        camera.frame(&sphere);
+                        println!("Decimated to {} faces", mesh.faces.len());
+                    }
+                    DrawEvent::KeyDown(_, Some(Key::KeyF)) => frustum = !frustum,
+                    DrawEvent::KeyDown(_, Some(Key::KeyE)) => match mesh.save("export.obj") {
+                        Ok(()) => println!("Exported {} to export.obj", mesh.name),
+                        Err(err) => eprintln!("Failed to export {}: {err}", mesh.name),
+                    },
+                    DrawEvent::Resize(width, height) => {
+                        viewport.resize(width as f32, height as f32);
+                        camera.aspect = viewport.aspect();
+                        camera.frame(&sphere);
+                        set_boundary(&canvas, &viewport);
+                    }
+                    DrawEvent::Scale(pixel_ratio) => {
+                        viewport.set_pixel_ratio(pixel_ratio as f32);
+                        set_boundary(&canvas, &viewport);
+                    }
+                    _ => {}
+                }
+            }
+
+            // camera.eye.z -= 1.0 * DELTA_TIME;
+
+            // Re-evaluate the formula for this frame, the grid's topology stays the same so only the vertices are replaced, subdivided copies included
+            if let Some(plot) = &plot {
+                time += constants::DELTA_TIME;
+                mesh.vertices = plot
+                    .sample(time)
+                    .iter()
+                    .map(|v| v.transform(&fit))
+                    .collect();
+
+                for model in &mut models {
+                    model.follow(&mesh);
+                }
+            }
+
+            // Compose the per-frame spin onto the current orientation, renormalizing to avoid drift
+            orientation = (spin * orientation).normalize();
 
             // TODO: It would be funny if it followed the mouse lol
 
-            // Apply translation, rotation, (maybe scale) to all vertices
-            let points: Vec<Point2D> = vs
-                .par_iter()
-                .map(|v| v.rotate_y(angle).translate_z(dz).project().screen())
-                .collect();
+            // Build the model-view-projection transform once per model (rotate, move into place, view, project) and apply it to all vertices
+            // Clip every edge against the near plane (or the whole frustum) before the perspective divide, keeping its color if it has one
+            let rotation = Mat4::from(orientation);
+            let view_projection = camera.view_projection();
+            let clip = if frustum {
+                clip::clip_frustum
+            } else {
+                clip::clip_near
+            };
+            let mut segments: Vec<(Point2D, Point2D, Option<Color>)> = Vec::new();
 
-            // Calculate all line segments
-            let mut segments: Vec<(&Point2D, &Point2D)> = Vec::with_capacity(vs.len() * 2);
+            for model in &models {
+                let Point3D { x, y, z } = model.offset;
+                let mvp = view_projection * Mat4::translation(x, y, z) * rotation;
 
-            for f in &fs {
-                for i in 0..f.len() {
-                    let a_idx = f[i];
-                    let b_idx = f[(i + 1) % f.len()];
-                    let a = &points[a_idx];
-                    let b = &points[b_idx];
+                let points: Vec<Point4D> = model
+                    .mesh
+                    .vertices
+                    .par_iter()
+                    .map(|v| v.to_clip(&mvp))
+                    .collect();
 
-                    segments.push((a, b));
+                for (i, edge) in model.edges.iter().enumerate() {
+                    if let Some((a, b)) = clip(&points[edge.a], &points[edge.b]) {
+                        segments.push((
+                            a.divide().ortho().screen(&viewport),
+                            b.divide().ortho().screen(&viewport),
+                            model.colors.as_ref().map(|colors| colors[i]),
+                        ));
+                    }
                 }
             }
 
@@ -122,10 +486,24 @@ pub fn main() {
             canvas.draw(|gc| {
                 gc.start_frame();
 
-                clear_layer(LayerId(1), gc);
-                // clear_layer(LayerId(2), gc);
+                clear_layer(LayerId(1), gc, &viewport);
+                // clear_layer(LayerId(2), gc, &viewport);
 
-                queue_lines(segments.iter().copied(), LayerId(1), gc);
+                queue_lines(
+                    segments
+                        .iter()
+                        .filter(|(_, _, color)| color.is_none())
+                        .map(|(a, b, _)| (a, b)),
+                    LayerId(1),
+                    gc,
+                );
+                queue_colored_lines(
+                    segments
+                        .iter()
+                        .filter_map(|(a, b, color)| Some((a, b, (*color)?))),
+                    LayerId(1),
+                    gc,
+                );
                 // queue_points(points.clone(), LayerId(2), gc);
 
                 gc.show_frame();
@@ -136,3 +514,19 @@ pub fn main() {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_builtin_is_valid() {
+        for name in BUILTINS {
+            let mesh = builtin(name).unwrap();
+            let report = mesh.validate();
+
+            assert!(report.is_valid(), "{name}:\n{report}");
+            assert!(!mesh.faces.is_empty() || !mesh.lines.is_empty(), "{name}");
+        }
+    }
+}
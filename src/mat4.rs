@@ -0,0 +1,262 @@
+use std::ops::Mul;
+
+use crate::point3d::Point3D;
+
+// Row-major 4x4 matrix operating on column vectors, so `a * b` applies `b` first and then `a`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4 {
+    pub rows: [[f32; 4]; 4],
+}
+
+impl Mat4 {
+    pub fn new(rows: [[f32; 4]; 4]) -> Self {
+        Self { rows }
+    }
+
+    pub fn identity() -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn translation(x: f32, y: f32, z: f32) -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Self {
+        Self::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    // All rotations take their angle in degrees
+    pub fn rotation_x(angle: f32) -> Self {
+        let c = f32::cos(angle.to_radians());
+        let s = f32::sin(angle.to_radians());
+
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, c, -s, 0.0],
+            [0.0, s, c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    // Same direction as the old Point3D::rotate_y, which turns +x towards +z (the opposite of rotation_x and rotation_z)
+    pub fn rotation_y(angle: f32) -> Self {
+        let c = f32::cos(angle.to_radians());
+        let s = f32::sin(angle.to_radians());
+
+        Self::new([
+            [c, 0.0, -s, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [s, 0.0, c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn rotation_z(angle: f32) -> Self {
+        let c = f32::cos(angle.to_radians());
+        let s = f32::sin(angle.to_radians());
+
+        Self::new([
+            [c, -s, 0.0, 0.0],
+            [s, c, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    // Rotation about an arbitrary axis through the origin (Rodrigues' formula), the axis does not need to be normalized
+    pub fn rotation(axis: &Point3D, angle: f32) -> Self {
+        if axis.length() == 0.0 {
+            return Self::identity();
+        }
+
+        let Point3D { x, y, z } = axis.normalize();
+        let c = f32::cos(angle.to_radians());
+        let s = f32::sin(angle.to_radians());
+        let t = 1.0 - c;
+
+        Self::new([
+            [t * x * x + c, t * x * y - s * z, t * x * z + s * y, 0.0],
+            [t * x * y + s * z, t * y * y + c, t * y * z - s * x, 0.0],
+            [t * x * z - s * y, t * y * z + s * x, t * z * z + c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    // Applies only the linear part of the matrix, for directions and normals that shouldn't be translated
+    pub fn transform_vector(&self, v: &Point3D) -> Point3D {
+        let r = &self.rows;
+
+        Point3D::new(
+            r[0][0] * v.x + r[0][1] * v.y + r[0][2] * v.z,
+            r[1][0] * v.x + r[1][1] * v.y + r[1][2] * v.z,
+            r[2][0] * v.x + r[2][1] * v.y + r[2][2] * v.z,
+        )
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut rows = [[0.0; 4]; 4];
+
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = self.rows[c][r];
+            }
+        }
+
+        Self::new(rows)
+    }
+
+    // Gauss-Jordan elimination with partial pivoting, returns None for singular matrices
+    pub fn inverse(&self) -> Option<Self> {
+        let mut a = self.rows;
+        let mut inv = Self::identity().rows;
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+                .unwrap_or(col);
+
+            if a[pivot][col].abs() <= f32::EPSILON {
+                return None;
+            }
+
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let p = a[col][col];
+
+            for c in 0..4 {
+                a[col][c] /= p;
+                inv[col][c] /= p;
+            }
+
+            for r in 0..4 {
+                if r == col {
+                    continue;
+                }
+
+                let factor = a[r][col];
+
+                for c in 0..4 {
+                    a[r][c] -= factor * a[col][c];
+                    inv[r][c] -= factor * inv[col][c];
+                }
+            }
+        }
+
+        Some(Self::new(inv))
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        let mut rows = [[0.0; 4]; 4];
+
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.rows[r][k] * rhs.rows[k][c]).sum();
+            }
+        }
+
+        Mat4::new(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: &Mat4, b: &Mat4) {
+        for (row_a, row_b) in a.rows.iter().zip(&b.rows) {
+            for (x, y) in row_a.iter().zip(row_b) {
+                assert!((x - y).abs() < 1e-5, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    fn assert_point(p: Point3D, x: f32, y: f32, z: f32) {
+        assert!(p.approx_eq(&Point3D::new(x, y, z), 1e-5), "{p:?}");
+    }
+
+    #[test]
+    fn multiply_applies_the_right_hand_side_first() {
+        let p = Point3D::new(1.0, 2.0, 3.0);
+        let scale_then_move = Mat4::translation(1.0, 0.0, 0.0) * Mat4::scale(2.0, 2.0, 2.0);
+        let move_then_scale = Mat4::scale(2.0, 2.0, 2.0) * Mat4::translation(1.0, 0.0, 0.0);
+
+        assert_point(p.transform(&scale_then_move), 3.0, 4.0, 6.0);
+        assert_point(p.transform(&move_then_scale), 4.0, 4.0, 6.0);
+        assert_eq!(scale_then_move * Mat4::identity(), scale_then_move);
+        assert_eq!(Mat4::identity() * scale_then_move, scale_then_move);
+    }
+
+    #[test]
+    fn rotations_follow_the_original_point_methods() {
+        let x = Point3D::new(1.0, 0.0, 0.0);
+        let y = Point3D::new(0.0, 1.0, 0.0);
+
+        assert_point(y.transform(&Mat4::rotation_x(90.0)), 0.0, 0.0, 1.0);
+        assert_point(x.transform(&Mat4::rotation_y(90.0)), 0.0, 0.0, 1.0);
+        assert_point(x.transform(&Mat4::rotation_z(90.0)), 0.0, 1.0, 0.0);
+        assert_near(
+            &Mat4::rotation(&Point3D::new(0.0, 0.0, 2.0), 30.0),
+            &Mat4::rotation_z(30.0),
+        );
+        assert_near(
+            &Mat4::rotation(&Point3D::new(0.0, -1.0, 0.0), 30.0),
+            &Mat4::rotation_y(30.0),
+        );
+    }
+
+    #[test]
+    fn inverse_undoes_the_transform() {
+        let m = Mat4::translation(1.0, -2.0, 3.0)
+            * Mat4::rotation(&Point3D::new(1.0, 1.0, 0.0), 40.0)
+            * Mat4::scale(2.0, 0.5, 4.0);
+        let inverse = m.inverse().unwrap();
+
+        assert_near(&(m * inverse), &Mat4::identity());
+        assert_near(&(inverse * m), &Mat4::identity());
+        assert_eq!(Mat4::scale(1.0, 0.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let m = Mat4::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+
+        assert_eq!(m.transpose().rows[0], [1.0, 5.0, 9.0, 13.0]);
+        assert_eq!(m.transpose().transpose(), m);
+
+        // A rotation's transpose is its inverse
+        let rotation = Mat4::rotation_y(25.0) * Mat4::rotation_x(-60.0);
+
+        assert_near(&(rotation * rotation.transpose()), &Mat4::identity());
+    }
+}
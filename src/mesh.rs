@@ -0,0 +1,413 @@
+use flo_canvas::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::bounds::{Bounds, Sphere};
+use crate::color;
+use crate::error::Error;
+use crate::mat4::Mat4;
+use crate::mesh::decimate::Target;
+use crate::mesh::subdivide::Scheme;
+use crate::mesh::validate::ValidationReport;
+use crate::point3d::Point3D;
+
+pub mod decimate;
+pub mod gltf;
+pub mod obj;
+pub mod ply;
+pub mod stl;
+pub mod subdivide;
+pub mod validate;
+
+// Undirected edge with `a < b`, `faces` lists the faces it borders (empty for edges that only come from lines)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge {
+    pub a: usize,
+    pub b: usize,
+    pub faces: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    pub name: String,
+    pub vertices: Vec<Point3D>,
+    // Closed polygons, the last index connects back to the first
+    pub faces: Vec<Vec<usize>>,
+    // Open polylines for edges that don't belong to a face
+    pub lines: Vec<Vec<usize>>,
+    pub vertex_colors: Option<Vec<Color>>,
+    pub vertex_normals: Option<Vec<Point3D>>,
+    // Keyed by the undirected edge (smaller index first), see Mesh::edge_key
+    pub edge_colors: Option<HashMap<(usize, usize), Color>>,
+}
+
+impl Mesh {
+    // Index lists with fewer than three entries can't enclose anything so they're kept as lines instead of faces
+    pub fn new(name: &str, vertices: Vec<Point3D>, indices: Vec<Vec<usize>>) -> Self {
+        let (faces, lines) = indices.into_iter().partition(|f| f.len() >= 3);
+
+        Self {
+            name: name.to_owned(),
+            vertices,
+            faces,
+            lines,
+            ..Default::default()
+        }
+    }
+
+    // Picks a loader from the file extension, files can hold more than one object
+    // Every mesh is validated, errors fail the load and each report is returned alongside its mesh for the warnings
+    pub fn load(path: impl AsRef<Path>) -> Result<Vec<(Mesh, ValidationReport)>, Error> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let meshes = match extension.as_str() {
+            "gltf" | "glb" => gltf::load(path)?,
+            "obj" => obj::load(path)?,
+            "ply" => ply::load(path)?,
+            "stl" => stl::load(path)?,
+            _ => {
+                return Err(Error::Unsupported(format!(
+                    "no loader for '{}'",
+                    path.display()
+                )));
+            }
+        };
+
+        meshes.into_iter().map(Mesh::checked).collect()
+    }
+
+    // Validates the mesh, errors turn it into Error::Invalid and otherwise the report comes back for its warnings
+    pub fn checked(self) -> Result<(Mesh, ValidationReport), Error> {
+        let report = self.validate();
+
+        if !report.is_valid() {
+            return Err(Error::Invalid {
+                name: self.name,
+                report,
+            });
+        }
+
+        Ok((self, report))
+    }
+
+    // Picks a writer from the file extension
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "obj" => obj::save(path, std::slice::from_ref(self)),
+            _ => Err(Error::Unsupported(format!(
+                "no writer for '{}'",
+                path.display()
+            ))),
+        }
+    }
+
+    // Combines several meshes into one, attributes survive only if every mesh has them
+    // A vertex that matches one from an earlier mesh (same position and attributes) is shared with it, so a surface
+    // split into OBJ groups stays connected and the edges along the split are only drawn once
+    pub fn merge(name: &str, meshes: &[Mesh]) -> Self {
+        let colors = !meshes.is_empty() && meshes.iter().all(|m| m.vertex_colors.is_some());
+        let normals = !meshes.is_empty() && meshes.iter().all(|m| m.vertex_normals.is_some());
+
+        let mut merged = Self {
+            name: name.to_owned(),
+            vertex_colors: colors.then(Vec::new),
+            vertex_normals: normals.then(Vec::new),
+            ..Default::default()
+        };
+        let mut shared: HashMap<Vec<u32>, usize> = HashMap::new();
+
+        for mesh in meshes {
+            let mut added = Vec::new();
+            let remap: Vec<usize> = (0..mesh.vertices.len())
+                .map(|v| {
+                    let color = mesh.vertex_colors.as_ref().filter(|_| colors).map(|c| c[v]);
+                    let normal = mesh
+                        .vertex_normals
+                        .as_ref()
+                        .filter(|_| normals)
+                        .map(|n| n[v]);
+
+                    // -0.0 and 0.0 are the same position but have different bits
+                    let p = mesh.vertices[v];
+                    let components = [p.x, p.y, p.z]
+                        .into_iter()
+                        .chain(
+                            color
+                                .map(|c| {
+                                    let (r, g, b, a) = c.to_rgba_components();
+                                    [r, g, b, a]
+                                })
+                                .into_iter()
+                                .flatten(),
+                        )
+                        .chain(normal.map(|n| [n.x, n.y, n.z]).into_iter().flatten());
+                    let key: Vec<u32> = components
+                        .map(|c| if c == 0.0 { 0 } else { c.to_bits() })
+                        .collect();
+
+                    if let Some(&index) = shared.get(&key) {
+                        return index;
+                    }
+
+                    merged.vertices.push(p);
+
+                    if let (Some(all), Some(color)) = (merged.vertex_colors.as_mut(), color) {
+                        all.push(color);
+                    }
+
+                    if let (Some(all), Some(normal)) = (merged.vertex_normals.as_mut(), normal) {
+                        all.push(normal);
+                    }
+
+                    added.push((key, merged.vertices.len() - 1));
+
+                    merged.vertices.len() - 1
+                })
+                .collect();
+
+            // Vertices within one mesh are never welded to each other, only to earlier meshes
+            shared.extend(added);
+
+            let renumber = |elements: &Vec<Vec<usize>>| -> Vec<Vec<usize>> {
+                elements
+                    .iter()
+                    .map(|e| e.iter().map(|&v| remap[v]).collect())
+                    .collect()
+            };
+
+            merged.faces.extend(renumber(&mesh.faces));
+            merged.lines.extend(renumber(&mesh.lines));
+
+            for (&(a, b), color) in mesh.edge_colors.iter().flatten() {
+                merged.set_edge_color(remap[a], remap[b], *color);
+            }
+        }
+
+        merged
+    }
+
+    pub fn with_lines(mut self, lines: Vec<Vec<usize>>) -> Self {
+        self.lines.extend(lines);
+        self
+    }
+
+    pub fn with_vertex_colors(mut self, colors: Vec<Color>) -> Self {
+        self.vertex_colors = Some(colors);
+        self
+    }
+
+    pub fn with_vertex_normals(mut self, normals: Vec<Point3D>) -> Self {
+        self.vertex_normals = Some(normals);
+        self
+    }
+
+    // Simplified copy with edges collapsed until the target is met, see decimate::decimate
+    pub fn decimate(&self, target: Target) -> Self {
+        decimate::decimate(self, target)
+    }
+
+    // Smoothed copy, each level splits every face, see subdivide::subdivide
+    pub fn subdivide(&self, scheme: Scheme, levels: usize) -> Self {
+        subdivide::subdivide(self, scheme, levels)
+    }
+
+    pub fn validate(&self) -> ValidationReport {
+        validate::validate(self)
+    }
+
+    pub fn edge_key(a: usize, b: usize) -> (usize, usize) {
+        (a.min(b), a.max(b))
+    }
+
+    pub fn set_edge_color(&mut self, a: usize, b: usize, color: Color) {
+        self.edge_colors
+            .get_or_insert_with(HashMap::new)
+            .insert(Self::edge_key(a, b), color);
+    }
+
+    pub fn edge_color(&self, a: usize, b: usize) -> Option<&Color> {
+        self.edge_colors.as_ref()?.get(&Self::edge_key(a, b))
+    }
+
+    // Every distinct edge once, in the order they first appear in the faces and then the lines
+    pub fn edges(&self) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = Vec::new();
+        let mut lookup: HashMap<(usize, usize), usize> = HashMap::new();
+
+        let face_edges = self.faces.iter().enumerate().flat_map(|(face, f)| {
+            (0..f.len()).map(move |i| (f[i], f[(i + 1) % f.len()], Some(face)))
+        });
+        let line_edges = self
+            .lines
+            .iter()
+            .flat_map(|l| l.windows(2).map(|w| (w[0], w[1], None)));
+
+        for (a, b, face) in face_edges.chain(line_edges) {
+            if a == b {
+                continue;
+            }
+
+            let key = Self::edge_key(a, b);
+            let index = *lookup.entry(key).or_insert_with(|| {
+                edges.push(Edge {
+                    a: key.0,
+                    b: key.1,
+                    faces: Vec::new(),
+                });
+                edges.len() - 1
+            });
+
+            if let Some(face) = face {
+                edges[index].faces.push(face);
+            }
+        }
+
+        edges
+    }
+
+    // Display color for each edge, an edge's own color wins over the blend of its endpoints' vertex colors
+    pub fn colors_for(&self, edges: &[Edge]) -> Option<Vec<Color>> {
+        if self.vertex_colors.is_none() && self.edge_colors.is_none() {
+            return None;
+        }
+
+        let fallback = *color::get_color();
+
+        let colors = edges
+            .iter()
+            .map(|edge| {
+                if let Some(color) = self.edge_color(edge.a, edge.b) {
+                    return *color;
+                }
+
+                let ends = self
+                    .vertex_colors
+                    .as_ref()
+                    .and_then(|colors| Some((colors.get(edge.a)?, colors.get(edge.b)?)));
+
+                match ends {
+                    Some((a, b)) => {
+                        let (r1, g1, b1, a1) = a.to_rgba_components();
+                        let (r2, g2, b2, a2) = b.to_rgba_components();
+
+                        Color::Rgba(
+                            (r1 + r2) / 2.0,
+                            (g1 + g2) / 2.0,
+                            (b1 + b2) / 2.0,
+                            (a1 + a2) / 2.0,
+                        )
+                    }
+                    None => fallback,
+                }
+            })
+            .collect();
+
+        Some(colors)
+    }
+
+    // None for meshes without vertices
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::from_points(&self.vertices)
+    }
+
+    // Centered on the bounding box, so after Mesh::fit it's centered on the origin and stays put while the model spins
+    pub fn bounding_sphere(&self) -> Option<Sphere> {
+        let center = self.bounds()?.center();
+
+        Some(Sphere::around(center, &self.vertices))
+    }
+
+    // Returns a copy moved to the origin and uniformly scaled so its longest side is `size` long
+    pub fn fit(&self, size: f32) -> Self {
+        self.transform(&self.fit_transform(size))
+    }
+
+    // The matrix Mesh::fit applies, for meshes whose vertices change after they've been fitted
+    pub fn fit_transform(&self, size: f32) -> Mat4 {
+        let Some(bounds) = self.bounds() else {
+            return Mat4::identity();
+        };
+
+        let center = bounds.center();
+        let scale = match bounds.extent() {
+            0.0 => 1.0,
+            extent => size / extent,
+        };
+
+        Mat4::scale(scale, scale, scale) * Mat4::translation(-center.x, -center.y, -center.z)
+    }
+
+    // Returns a copy with the matrix applied to every vertex, normals use the inverse transpose so they stay perpendicular
+    pub fn transform(&self, m: &Mat4) -> Self {
+        let normal_matrix = m.inverse().unwrap_or_default().transpose();
+
+        Self {
+            vertices: self.vertices.iter().map(|v| v.transform(m)).collect(),
+            vertex_normals: self.vertex_normals.as_ref().map(|normals| {
+                normals
+                    .iter()
+                    .map(|n| normal_matrix.transform_vector(n).normalize())
+                    .collect()
+            }),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_shares_vertices_between_groups() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\ng left\nf 1 2 3\ng right\nf 2 4 3\n";
+        let meshes = obj::read(source.as_bytes()).unwrap();
+        let merged = Mesh::merge("quad", &meshes);
+
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(merged.vertices.len(), 4);
+        assert_eq!(merged.faces, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+        assert_eq!(merged.edges().len(), 5);
+    }
+
+    #[test]
+    fn merge_keeps_vertices_with_different_attributes_apart() {
+        let triangle = |color: Color| {
+            Mesh::new(
+                "triangle",
+                vec![
+                    Point3D::new(0.0, 0.0, 0.0),
+                    Point3D::new(1.0, 0.0, 0.0),
+                    Point3D::new(0.0, 1.0, 0.0),
+                ],
+                vec![vec![0, 1, 2]],
+            )
+            .with_vertex_colors(vec![color; 3])
+        };
+        let red = Color::Rgba(1.0, 0.0, 0.0, 1.0);
+        let blue = Color::Rgba(0.0, 0.0, 1.0, 1.0);
+
+        assert_eq!(
+            Mesh::merge("same", &[triangle(red), triangle(red)])
+                .vertices
+                .len(),
+            3
+        );
+        assert_eq!(
+            Mesh::merge("different", &[triangle(red), triangle(blue)])
+                .vertices
+                .len(),
+            6
+        );
+    }
+}
@@ -0,0 +1,479 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// How far to simplify, whichever comes first of the count being reached or no collapse being cheap enough
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Target {
+    Vertices(usize),
+    Faces(usize),
+    // Largest quadric cost a collapse may have, the summed squared distances to the planes it merged,
+    // with the planes along open edges counting BOUNDARY_WEIGHT times as much
+    Error(f32),
+}
+
+// Boundary edges get a steep quadric across them so open edges (and the silhouette of flat patches) stay put
+const BOUNDARY_WEIGHT: f64 = 1000.0;
+
+// Symmetric 4x4 matrix of summed plane equations, stored as its upper triangle
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    fn plane(normal: &Point3D, point: &Point3D, weight: f64) -> Self {
+        let (a, b, c) = (normal.x as f64, normal.y as f64, normal.z as f64);
+        let d = -(normal.dot(point) as f64);
+
+        Self(
+            [
+                a * a,
+                a * b,
+                a * c,
+                a * d,
+                b * b,
+                b * c,
+                b * d,
+                c * c,
+                c * d,
+                d * d,
+            ]
+            .map(|q| q * weight),
+        )
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+
+    // Sum of squared distances from p to every plane in the quadric
+    fn error(&self, p: &Point3D) -> f64 {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.0;
+        let (x, y, z) = (p.x as f64, p.y as f64, p.z as f64);
+
+        aa * x * x
+            + 2.0 * ab * x * y
+            + 2.0 * ac * x * z
+            + 2.0 * ad * x
+            + bb * y * y
+            + 2.0 * bc * y * z
+            + 2.0 * bd * y
+            + cc * z * z
+            + 2.0 * cd * z
+            + dd
+    }
+
+    // Point with the smallest error, None when the planes don't pin one down (flat or straight regions)
+    fn minimum(&self) -> Option<Point3D> {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, _] = self.0;
+        let det = aa * (bb * cc - bc * bc) - ab * (ab * cc - bc * ac) + ac * (ab * bc - bb * ac);
+
+        if det.abs() <= 1e-12 {
+            return None;
+        }
+
+        // Cramer's rule on the 3x3 system with -(ad, bd, cd) on the right
+        let (rx, ry, rz) = (-ad, -bd, -cd);
+        let x = rx * (bb * cc - bc * bc) - ab * (ry * cc - bc * rz) + ac * (ry * bc - bb * rz);
+        let y = aa * (ry * cc - bc * rz) - rx * (ab * cc - bc * ac) + ac * (ab * rz - ry * ac);
+        let z = aa * (bb * rz - ry * bc) - ab * (ab * rz - ry * ac) + rx * (ab * bc - bb * ac);
+
+        Some(Point3D::new(
+            (x / det) as f32,
+            (y / det) as f32,
+            (z / det) as f32,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    a: usize,
+    b: usize,
+    // Versions of both vertices when this was queued, a collapse touching either one makes it stale
+    versions: (usize, usize),
+    position: Point3D,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Reversed so the BinaryHeap pops the cheapest collapse first
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+// Garland-Heckbert edge collapse, polygons are triangulated first so the result is always triangles
+// Vertices used by lines are never moved, colors and normals follow the vertex that survives each collapse
+pub fn decimate(mesh: &Mesh, target: Target) -> Mesh {
+    let mut positions = mesh.vertices.clone();
+    let mut faces: Vec<Option<[usize; 3]>> = mesh
+        .faces
+        .iter()
+        .flat_map(|f| (1..f.len().saturating_sub(1)).map(move |i| [f[0], f[i], f[i + 1]]))
+        .filter(|[a, b, c]| a != b && b != c && c != a)
+        .map(Some)
+        .collect();
+
+    let mut around: Vec<HashSet<usize>> = vec![HashSet::new(); positions.len()];
+
+    for (i, face) in faces.iter().enumerate() {
+        for &v in face.iter().flatten() {
+            around[v].insert(i);
+        }
+    }
+
+    let locked: HashSet<usize> = mesh.lines.iter().flatten().copied().collect();
+    let mut quadrics = vec![Quadric::default(); positions.len()];
+
+    for [a, b, c] in faces.iter().flatten() {
+        let normal = face_normal(&positions, &[*a, *b, *c]).normalize();
+        let plane = Quadric::plane(&normal, &positions[*a], 1.0);
+
+        for v in [a, b, c] {
+            quadrics[*v] = quadrics[*v].add(&plane);
+        }
+    }
+
+    // Edges with a single face are on the boundary, constrain them with a plane through the edge perpendicular to the face
+    let mut uses: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for (i, [a, b, c]) in faces
+        .iter()
+        .enumerate()
+        .filter_map(|(i, f)| Some((i, (*f)?)))
+    {
+        for (p, q) in [(a, b), (b, c), (c, a)] {
+            uses.entry(Mesh::edge_key(p, q)).or_default().push(i);
+        }
+    }
+
+    for (&(a, b), faces_using) in &uses {
+        if let [face] = faces_using.as_slice() {
+            let normal = face_normal(&positions, &faces[*face].unwrap_or_default());
+            let across = (positions[b] - positions[a]).cross(&normal).normalize();
+            let plane = Quadric::plane(&across, &positions[a], BOUNDARY_WEIGHT);
+
+            quadrics[a] = quadrics[a].add(&plane);
+            quadrics[b] = quadrics[b].add(&plane);
+        }
+    }
+
+    let mut versions = vec![0; positions.len()];
+    let mut alive = vec![false; positions.len()];
+
+    for face in faces.iter().flatten() {
+        for &v in face {
+            alive[v] = true;
+        }
+    }
+
+    let candidate =
+        |a: usize, b: usize, positions: &[Point3D], quadrics: &[Quadric], versions: &[usize]| {
+            // A locked vertex has to be the one that survives
+            let (a, b) = match locked.contains(&b) {
+                true => (b, a),
+                false => (a, b),
+            };
+            let quadric = quadrics[a].add(&quadrics[b]);
+            let position = match (locked.contains(&a), locked.contains(&b)) {
+                (true, true) => return None,
+                (true, false) => positions[a],
+                _ => quadric.minimum().unwrap_or_else(|| {
+                    // Fall back to whichever of the ends or the middle fits best
+                    [
+                        positions[a],
+                        positions[b],
+                        positions[a].lerp(&positions[b], 0.5),
+                    ]
+                    .into_iter()
+                    .min_by(|p, q| quadric.error(p).total_cmp(&quadric.error(q)))
+                    .unwrap_or(positions[a])
+                }),
+            };
+
+            Some(Collapse {
+                cost: quadric.error(&position).max(0.0),
+                a,
+                b,
+                versions: (versions[a], versions[b]),
+                position,
+            })
+        };
+
+    let mut queue: BinaryHeap<Collapse> = uses
+        .keys()
+        .filter_map(|&(a, b)| candidate(a, b, &positions, &quadrics, &versions))
+        .collect();
+
+    let mut face_count = faces.iter().flatten().count();
+    let mut vertex_count = alive.iter().filter(|&&a| a).count();
+
+    while let Some(collapse) = queue.pop() {
+        let done = match target {
+            Target::Vertices(count) => vertex_count <= count,
+            Target::Faces(count) => face_count <= count,
+            Target::Error(error) => collapse.cost > error as f64,
+        };
+
+        if done {
+            break;
+        }
+
+        let Collapse { a, b, position, .. } = collapse;
+
+        if !alive[a] || !alive[b] || collapse.versions != (versions[a], versions[b]) {
+            continue;
+        }
+
+        if !keeps_manifold(&faces, &around, a, b)
+            || flips(&faces, &around, &positions, a, b, &position)
+        {
+            continue;
+        }
+
+        // Merge b into a, faces that had both collapse to nothing
+        for f in around[b].clone() {
+            let Some(face) = faces[f].as_mut() else {
+                continue;
+            };
+
+            if face.contains(&a) {
+                for v in *face {
+                    around[v].remove(&f);
+                }
+
+                faces[f] = None;
+                face_count -= 1;
+            } else {
+                for v in face.iter_mut().filter(|v| **v == b) {
+                    *v = a;
+                }
+
+                around[a].insert(f);
+            }
+        }
+
+        around[b].clear();
+        alive[b] = false;
+        vertex_count -= 1;
+
+        positions[a] = position;
+        quadrics[a] = quadrics[a].add(&quadrics[b]);
+        versions[a] += 1;
+
+        for n in neighbours(&faces, &around, a) {
+            queue.extend(candidate(a, n, &positions, &quadrics, &versions));
+        }
+    }
+
+    // Drop the merged away vertices and renumber what's left, keeping each survivor's attributes
+    let mut remap: Vec<Option<usize>> = vec![None; positions.len()];
+    let mut kept = Vec::new();
+
+    for (v, used) in alive.iter().enumerate() {
+        if *used || locked.contains(&v) {
+            remap[v] = Some(kept.len());
+            kept.push(v);
+        }
+    }
+
+    let renumber = |v: &usize| remap[*v].unwrap_or_default();
+
+    Mesh {
+        name: mesh.name.clone(),
+        vertices: kept.iter().map(|&v| positions[v]).collect(),
+        faces: faces
+            .iter()
+            .flatten()
+            .map(|f| f.iter().map(renumber).collect())
+            .collect(),
+        lines: mesh
+            .lines
+            .iter()
+            .map(|l| l.iter().map(renumber).collect())
+            .collect(),
+        vertex_colors: mesh
+            .vertex_colors
+            .as_ref()
+            .map(|colors| pick(colors, &kept)),
+        vertex_normals: mesh
+            .vertex_normals
+            .as_ref()
+            .map(|normals| pick(normals, &kept)),
+        edge_colors: mesh.edge_colors.as_ref().map(|colors| {
+            colors
+                .iter()
+                .filter_map(|(&(a, b), color)| Some((Mesh::edge_key(remap[a]?, remap[b]?), *color)))
+                .collect()
+        }),
+    }
+}
+
+fn pick<T: Copy>(values: &[T], kept: &[usize]) -> Vec<T> {
+    kept.iter().map(|&v| values[v]).collect()
+}
+
+fn face_normal(positions: &[Point3D], [a, b, c]: &[usize; 3]) -> Point3D {
+    (positions[*b] - positions[*a]).cross(&(positions[*c] - positions[*a]))
+}
+
+fn neighbours(faces: &[Option<[usize; 3]>], around: &[HashSet<usize>], v: usize) -> HashSet<usize> {
+    around[v]
+        .iter()
+        .filter_map(|&f| faces[f])
+        .flatten()
+        .filter(|&n| n != v)
+        .collect()
+}
+
+// Link condition, the only vertices a and b may share are the ones opposite their edge, otherwise the collapse pinches the surface
+fn keeps_manifold(
+    faces: &[Option<[usize; 3]>],
+    around: &[HashSet<usize>],
+    a: usize,
+    b: usize,
+) -> bool {
+    let shared = neighbours(faces, around, a)
+        .intersection(&neighbours(faces, around, b))
+        .count();
+    let opposite = around[a]
+        .intersection(&around[b])
+        .filter(|&&f| faces[f].is_some())
+        .count();
+
+    if shared != opposite {
+        return false;
+    }
+
+    // Collapsing the far edge of an ear on the boundary would leave its tip without any faces
+    let stranded = around[a]
+        .intersection(&around[b])
+        .filter_map(|&f| faces[f])
+        .flatten()
+        .filter(|&v| v != a && v != b)
+        .any(|v| around[v].iter().filter(|&&f| faces[f].is_some()).count() < 2);
+
+    if stranded {
+        return false;
+    }
+
+    // Small closed pieces (a tetrahedron) would otherwise fold into two faces on top of each other
+    let mut remaining = HashSet::new();
+
+    around[a]
+        .union(&around[b])
+        .filter_map(|&f| faces[f])
+        .filter(|face| !(face.contains(&a) && face.contains(&b)))
+        .all(|face| {
+            let mut key = face.map(|v| if v == b { a } else { v });
+            key.sort_unstable();
+            remaining.insert(key)
+        })
+}
+
+// Whether moving a and b to `position` turns any of their remaining faces over
+// Faces that already have no area have no direction to flip from, so they're left to the link condition
+fn flips(
+    faces: &[Option<[usize; 3]>],
+    around: &[HashSet<usize>],
+    positions: &[Point3D],
+    a: usize,
+    b: usize,
+    position: &Point3D,
+) -> bool {
+    around[a]
+        .union(&around[b])
+        .filter_map(|&f| faces[f])
+        .any(|face| {
+            if face.contains(&a) && face.contains(&b) {
+                return false;
+            }
+
+            let before = face_normal(positions, &face);
+            let (p, q, r) = (positions[face[0]], positions[face[1]], positions[face[2]]);
+
+            if before.length() <= (q - p).length() * (r - p).length() * 1e-6 {
+                return false;
+            }
+
+            let moved = face.map(|v| {
+                if v == a || v == b {
+                    *position
+                } else {
+                    positions[v]
+                }
+            });
+            let after = (moved[1] - moved[0]).cross(&(moved[2] - moved[0]));
+
+            before.dot(&after) < 0.0
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::grid::Grid;
+    use crate::shape::icosphere::Icosphere;
+
+    #[test]
+    fn decimated_spheres_stay_closed() {
+        let sphere = Icosphere::default().build();
+        let decimated = sphere.decimate(Target::Vertices(40));
+
+        assert!(sphere.vertices.len() > 40);
+        assert_eq!(decimated.vertices.len(), 40);
+        assert_eq!(decimated.validate().issues, vec![]);
+
+        // Euler characteristic of a sphere, every edge still has a face on each side
+        let edges = decimated.edges().len();
+        assert_eq!(decimated.vertices.len() + decimated.faces.len(), edges + 2);
+        assert_eq!(decimated.faces.len() * 3, edges * 2);
+    }
+
+    #[test]
+    fn decimated_grids_keep_their_outline() {
+        let grid = Grid::default().build();
+        let decimated = grid.decimate(Target::Faces(20));
+        let (before, after) = (grid.bounds().unwrap(), decimated.bounds().unwrap());
+
+        assert!(decimated.faces.len() <= 20);
+        assert!(decimated.validate().is_valid());
+        assert!(after.min.approx_eq(&before.min, 1e-4));
+        assert!(after.max.approx_eq(&before.max, 1e-4));
+    }
+
+    #[test]
+    fn flat_surfaces_collapse_for_free() {
+        let grid = Grid::default().build();
+        let decimated = grid.decimate(Target::Error(1e-6));
+
+        assert!(decimated.faces.len() < grid.faces.len());
+        assert!(decimated.vertices.iter().all(|v| v.y.abs() < 1e-5));
+    }
+
+    #[test]
+    fn lines_keep_their_vertices() {
+        let grid = Grid::default().build().with_lines(vec![vec![0, 60]]);
+        let decimated = grid.decimate(Target::Faces(10));
+        let line = &decimated.lines[0];
+
+        assert!(decimated.vertices[line[0]].approx_eq(&grid.vertices[0], 1e-6));
+        assert!(decimated.vertices[line[1]].approx_eq(&grid.vertices[60], 1e-6));
+    }
+}
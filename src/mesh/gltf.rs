@@ -0,0 +1,384 @@
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use flo_canvas::Color;
+use gltf::buffer::Source;
+use gltf::mesh::Mode;
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::mat4::Mat4;
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Handles both .gltf (JSON with external or embedded buffers) and .glb (binary container)
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Mesh>, Error> {
+    let path = path.as_ref();
+
+    read(&fs::read(path)?, path.parent())
+}
+
+// One mesh per node that references a mesh, with the node hierarchy's transforms baked into the vertices
+pub fn read(bytes: &[u8], base: Option<&Path>) -> Result<Vec<Mesh>, Error> {
+    let gltf::Gltf { document, blob } = gltf::Gltf::from_slice(bytes).map_err(gltf_error)?;
+
+    let buffers = document
+        .buffers()
+        .map(|buffer| match buffer.source() {
+            Source::Bin => blob
+                .clone()
+                .ok_or_else(|| Error::parse(0, "GLB buffer is missing its binary chunk")),
+            Source::Uri(uri) => read_uri(uri, base),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut meshes = Vec::new();
+
+    match document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+    {
+        Some(scene) => {
+            for node in scene.nodes() {
+                visit(&node, &Mat4::identity(), &buffers, &mut meshes)?;
+            }
+        }
+        // Files without scenes still have meshes worth looking at
+        None => {
+            for mesh in document.meshes() {
+                meshes.push(read_mesh(&mesh, &buffers)?);
+            }
+        }
+    }
+
+    Ok(meshes)
+}
+
+fn visit(
+    node: &gltf::Node,
+    parent: &Mat4,
+    buffers: &[Vec<u8>],
+    meshes: &mut Vec<Mesh>,
+) -> Result<(), Error> {
+    // glTF matrices are column-major
+    let columns = node.transform().matrix();
+    let local = Mat4::new(std::array::from_fn(|r| {
+        std::array::from_fn(|c| columns[c][r])
+    }));
+    let world = *parent * local;
+
+    if let Some(mesh) = node.mesh() {
+        let mut result = read_mesh(&mesh, buffers)?.transform(&world);
+
+        if let Some(name) = node.name() {
+            result.name = name.to_owned();
+        }
+
+        meshes.push(result);
+    }
+
+    for child in node.children() {
+        visit(&child, &world, buffers, meshes)?;
+    }
+
+    Ok(())
+}
+
+// All primitives of a mesh are merged, the topology comes from each primitive's mode
+fn read_mesh(mesh: &gltf::Mesh, buffers: &[Vec<u8>]) -> Result<Mesh, Error> {
+    let mut primitives = Vec::new();
+
+    for primitive in mesh.primitives() {
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|b| b.as_slice()));
+        let vertices: Vec<Point3D> = reader
+            .read_positions()
+            .ok_or_else(|| Error::parse(0, "primitive has no POSITION attribute"))?
+            .map(|[x, y, z]| Point3D::new(x, y, z))
+            .collect();
+
+        let indices: Vec<usize> = match reader.read_indices() {
+            Some(indices) => indices.into_u32().map(|i| i as usize).collect(),
+            None => (0..vertices.len()).collect(),
+        };
+
+        let mut faces: Vec<Vec<usize>> = Vec::new();
+        let mut lines: Vec<Vec<usize>> = Vec::new();
+
+        match primitive.mode() {
+            Mode::Points => {}
+            Mode::Lines => lines.extend(indices.chunks_exact(2).map(|l| l.to_vec())),
+            Mode::LineStrip => lines.push(indices.clone()),
+            Mode::LineLoop => lines.push(indices.iter().chain(indices.first()).copied().collect()),
+            Mode::Triangles => faces.extend(indices.chunks_exact(3).map(|f| f.to_vec())),
+            // Every other strip triangle is flipped to keep the winding consistent
+            Mode::TriangleStrip => {
+                faces.extend(indices.windows(3).enumerate().map(|(i, w)| match i % 2 {
+                    0 => vec![w[0], w[1], w[2]],
+                    _ => vec![w[1], w[0], w[2]],
+                }))
+            }
+            Mode::TriangleFan => faces.extend(
+                indices
+                    .windows(2)
+                    .skip(1)
+                    .map(|w| vec![indices[0], w[0], w[1]]),
+            ),
+        }
+
+        let mut result = Mesh::new("", vertices, faces).with_lines(lines);
+
+        if let Some(normals) = reader.read_normals() {
+            result = result
+                .with_vertex_normals(normals.map(|[x, y, z]| Point3D::new(x, y, z)).collect());
+        }
+
+        if let Some(colors) = reader.read_colors(0) {
+            result = result.with_vertex_colors(
+                colors
+                    .into_rgba_f32()
+                    .map(|[r, g, b, a]| Color::Rgba(r, g, b, a))
+                    .collect(),
+            );
+        }
+
+        primitives.push(result);
+    }
+
+    Ok(Mesh::merge(mesh.name().unwrap_or_default(), &primitives))
+}
+
+// Buffers are either base64 data URIs or paths relative to the .gltf file
+fn read_uri(uri: &str, base: Option<&Path>) -> Result<Vec<u8>, Error> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        let (_, encoded) = data
+            .split_once(";base64,")
+            .ok_or_else(|| Error::Unsupported("data URI without base64 encoding".to_owned()))?;
+
+        return STANDARD
+            .decode(encoded)
+            .map_err(|err| Error::parse(0, format!("invalid base64 buffer: {err}")));
+    }
+
+    let path = percent_decode(uri);
+
+    Ok(fs::read(match base {
+        Some(base) => base.join(path),
+        None => path.into(),
+    })?)
+}
+
+fn percent_decode(uri: &str) -> String {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn gltf_error(err: gltf::Error) -> Error {
+    match err {
+        gltf::Error::Io(err) => Error::Io(err),
+        err => Error::parse(0, format!("glTF: {err}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A single-primitive glTF with its buffer embedded as a data URI
+    fn document(positions: &[[f32; 3]], indices: Option<&[u16]>, mode: u32, nodes: &str) -> String {
+        let mut bytes: Vec<u8> = positions
+            .iter()
+            .flatten()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let offset = bytes.len();
+
+        if let Some(indices) = indices {
+            bytes.extend(indices.iter().flat_map(|i| i.to_le_bytes()));
+        }
+
+        let mut accessors = vec![format!(
+            r#"{{"bufferView": 0, "componentType": 5126, "count": {}, "type": "VEC3", "min": [-10, -10, -10], "max": [10, 10, 10]}}"#,
+            positions.len()
+        )];
+        let mut views = vec![format!(r#"{{"buffer": 0, "byteLength": {offset}}}"#)];
+        let mut primitive = format!(r#"{{"attributes": {{"POSITION": 0}}, "mode": {mode}"#);
+
+        if let Some(indices) = indices {
+            accessors.push(format!(
+                r#"{{"bufferView": 1, "componentType": 5123, "count": {}, "type": "SCALAR"}}"#,
+                indices.len()
+            ));
+            views.push(format!(
+                r#"{{"buffer": 0, "byteOffset": {offset}, "byteLength": {}}}"#,
+                indices.len() * 2
+            ));
+            primitive.push_str(r#", "indices": 1"#);
+        }
+
+        format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "scene": 0,
+                "scenes": [{{"nodes": [0]}}],
+                "nodes": {nodes},
+                "meshes": [{{"primitives": [{primitive}}}]}}],
+                "accessors": [{}],
+                "bufferViews": [{}],
+                "buffers": [{{"byteLength": {}, "uri": "data:application/octet-stream;base64,{}"}}]
+            }}"#,
+            accessors.join(", "),
+            views.join(", "),
+            bytes.len(),
+            STANDARD.encode(&bytes),
+        )
+    }
+
+    fn faces(mode: u32, count: usize) -> Vec<Vec<usize>> {
+        let positions: Vec<[f32; 3]> = (0..count)
+            .map(|i| [i as f32, (i % 2) as f32, 0.0])
+            .collect();
+        let source = document(&positions, None, mode, r#"[{"mesh": 0}]"#);
+
+        read(source.as_bytes(), None).unwrap()[0].faces.clone()
+    }
+
+    fn parse_error(source: &str) -> String {
+        match read(source.as_bytes(), None) {
+            Err(Error::Parse { line: 0, message }) => message,
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_indexed_triangles() {
+        let positions = [
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ];
+        let source = document(&positions, Some(&[0, 1, 2, 0, 2, 3]), 4, r#"[{"mesh": 0}]"#);
+        let meshes = read(source.as_bytes(), None).unwrap();
+
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes[0].vertices.len(), 4);
+        assert_eq!(meshes[0].vertices[2], Point3D::new(1.0, 1.0, 0.0));
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn reads_non_indexed_triangles_in_order() {
+        assert_eq!(faces(4, 6), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    fn strips_keep_a_consistent_winding() {
+        assert_eq!(
+            faces(5, 5),
+            vec![vec![0, 1, 2], vec![2, 1, 3], vec![2, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn fans_share_the_first_vertex() {
+        assert_eq!(
+            faces(6, 5),
+            vec![vec![0, 1, 2], vec![0, 2, 3], vec![0, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn reads_lines() {
+        let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]];
+        let source = document(&positions, None, 2, r#"[{"mesh": 0}]"#);
+        let meshes = read(source.as_bytes(), None).unwrap();
+
+        assert!(meshes[0].faces.is_empty());
+        assert_eq!(meshes[0].lines, vec![vec![0, 1, 2, 0]]);
+    }
+
+    #[test]
+    fn composes_node_transforms_from_the_root_down() {
+        let nodes = r#"[
+            {"translation": [1, 0, 0], "children": [1]},
+            {"name": "child", "mesh": 0, "translation": [0, 1, 0], "scale": [2, 2, 2]}
+        ]"#;
+        let source = document(
+            &[[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]],
+            None,
+            4,
+            nodes,
+        );
+        let meshes = read(source.as_bytes(), None).unwrap();
+
+        assert_eq!(meshes[0].name, "child");
+        assert_eq!(meshes[0].vertices[0], Point3D::new(3.0, 1.0, 0.0));
+        assert_eq!(meshes[0].vertices[1], Point3D::new(1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn applies_node_matrices_column_major() {
+        let nodes = r#"[{"mesh": 0, "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 7, 1]}]"#;
+        let source = document(
+            &[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            None,
+            4,
+            nodes,
+        );
+        let meshes = read(source.as_bytes(), None).unwrap();
+
+        assert_eq!(meshes[0].vertices[0], Point3D::new(6.0, 6.0, 7.0));
+    }
+
+    #[test]
+    fn rejects_data_uris_without_base64() {
+        let source = document(&[[0.0; 3]; 3], None, 4, r#"[{"mesh": 0}]"#).replace(";base64,", ",");
+
+        assert!(matches!(
+            read(source.as_bytes(), None),
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn reports_invalid_base64() {
+        let source =
+            document(&[[0.0; 3]; 3], None, 4, r#"[{"mesh": 0}]"#).replace("base64,", "base64,!!");
+
+        assert!(parse_error(&source).starts_with("invalid base64 buffer"));
+    }
+
+    #[test]
+    fn reports_malformed_documents() {
+        assert!(parse_error("{").starts_with("glTF: "));
+    }
+
+    #[test]
+    fn reports_primitives_without_positions() {
+        let source = document(&[[0.0; 3]; 3], None, 4, r#"[{"mesh": 0}]"#)
+            .replace(r#""POSITION": 0"#, r#""NORMAL": 0"#);
+
+        assert!(parse_error(&source).contains("POSITION"));
+    }
+}
@@ -0,0 +1,485 @@
+use flo_canvas::Color;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::error::Error;
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Faces and lines collected under an `o` or `g` statement, indices point into the file-wide vertex list
+#[derive(Default)]
+struct Group {
+    name: String,
+    faces: Vec<Vec<usize>>,
+    lines: Vec<Vec<usize>>,
+}
+
+impl Group {
+    fn is_empty(&self) -> bool {
+        self.faces.is_empty() && self.lines.is_empty()
+    }
+}
+
+// Loads every object in the file, unnamed objects are named after the file
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Mesh>, Error> {
+    let path = path.as_ref();
+    let mut meshes = read(BufReader::new(File::open(path)?))?;
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    for mesh in meshes.iter_mut().filter(|m| m.name.is_empty()) {
+        mesh.name = stem.clone();
+    }
+
+    Ok(meshes)
+}
+
+// One mesh per `o`/`g` group that has elements, every vertex is kept so writing and reading round-trips
+// A group gets the vertices it uses plus any unused ones defined under it, a file without groups becomes a single mesh
+pub fn read<R: BufRead>(reader: R) -> Result<Vec<Mesh>, Error> {
+    let mut positions: Vec<Point3D> = Vec::new();
+    let mut colors: Vec<Option<Color>> = Vec::new();
+    let mut normals: Vec<Point3D> = Vec::new();
+    let mut texcoords = 0;
+
+    // Normal index used by each position, the last reference wins
+    let mut vertex_normals: Vec<Option<usize>> = Vec::new();
+    // Group each position was defined under, vertices no element uses stay with it
+    let mut owners: Vec<usize> = Vec::new();
+    let mut groups = vec![Group::default()];
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = number + 1;
+        let content = line.split('#').next().unwrap_or_default();
+        let mut tokens = content.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let values = parse_floats(tokens, number)?;
+
+                if values.len() < 3 {
+                    return Err(Error::parse(number, "vertex needs x, y and z"));
+                }
+
+                positions.push(Point3D::new(values[0], values[1], values[2]));
+                vertex_normals.push(None);
+                owners.push(groups.len() - 1);
+
+                // Common extension: `v x y z r g b` carries a vertex color
+                colors.push(match values.len() {
+                    6.. => Some(Color::Rgba(values[3], values[4], values[5], 1.0)),
+                    _ => None,
+                });
+            }
+            Some("vn") => {
+                let values = parse_floats(tokens, number)?;
+
+                if values.len() < 3 {
+                    return Err(Error::parse(number, "normal needs x, y and z"));
+                }
+
+                normals.push(Point3D::new(values[0], values[1], values[2]));
+            }
+            Some("vt") => texcoords += 1,
+            Some("f") => {
+                let mut face = Vec::new();
+
+                for token in tokens {
+                    let mut parts = token.split('/');
+                    let position = resolve(parts.next(), positions.len(), number)?;
+
+                    if let Some(texcoord) = parts.next().filter(|t| !t.is_empty()) {
+                        resolve(Some(texcoord), texcoords, number)?;
+                    }
+
+                    if let Some(normal) = parts.next().filter(|n| !n.is_empty()) {
+                        vertex_normals[position] =
+                            Some(resolve(Some(normal), normals.len(), number)?);
+                    }
+
+                    face.push(position);
+                }
+
+                if face.len() < 3 {
+                    return Err(Error::parse(number, "face needs at least three vertices"));
+                }
+
+                current(&mut groups).faces.push(face);
+            }
+            Some("l") => {
+                let line = tokens
+                    .map(|token| resolve(token.split('/').next(), positions.len(), number))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if line.len() < 2 {
+                    return Err(Error::parse(number, "line needs at least two vertices"));
+                }
+
+                current(&mut groups).lines.push(line);
+            }
+            Some("o") | Some("g") => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+
+                if !current(&mut groups).is_empty() {
+                    groups.push(Group::default());
+                }
+
+                current(&mut groups).name = name;
+            }
+            // Materials, smoothing groups and anything else don't affect the wireframe
+            _ => {}
+        }
+    }
+
+    let normal_of = |v: usize| vertex_normals[v].map(|n| normals[n]);
+    let filled: Vec<usize> = (0..groups.len())
+        .filter(|&g| !groups[g].is_empty())
+        .collect();
+
+    let meshes = match filled.as_slice() {
+        [] | [_] => {
+            let group = filled.first().map_or(&groups[0], |&g| &groups[g]);
+            let all: Vec<usize> = (0..positions.len()).collect();
+
+            vec![build(group, &all, &positions, &colors, &normal_of)]
+        }
+        _ => {
+            let mut referenced = vec![false; positions.len()];
+
+            for &v in groups
+                .iter()
+                .flat_map(|g| g.faces.iter().chain(&g.lines))
+                .flatten()
+            {
+                referenced[v] = true;
+            }
+
+            filled
+                .iter()
+                .enumerate()
+                .map(|(k, &g)| {
+                    let group = &groups[g];
+                    let mut seen = vec![false; positions.len()];
+
+                    for &v in group.faces.iter().chain(&group.lines).flatten() {
+                        seen[v] = true;
+                    }
+
+                    // Unused vertices from groups without elements fall through to the next group that has some
+                    let first = if k == 0 { 0 } else { filled[k - 1] + 1 };
+                    let last = if k + 1 == filled.len() { usize::MAX } else { g };
+
+                    for v in 0..positions.len() {
+                        if !referenced[v] && (first..=last).contains(&owners[v]) {
+                            seen[v] = true;
+                        }
+                    }
+
+                    // In file order so writing and reading round-trips
+                    let used: Vec<usize> = (0..positions.len()).filter(|&v| seen[v]).collect();
+
+                    build(group, &used, &positions, &colors, &normal_of)
+                })
+                .collect()
+        }
+    };
+
+    Ok(meshes)
+}
+
+pub fn save(path: impl AsRef<Path>, meshes: &[Mesh]) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    write(&mut writer, meshes)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+// Each mesh becomes its own `o` object, vertex colors use the `v x y z r g b` extension
+pub fn write<W: Write>(mut writer: W, meshes: &[Mesh]) -> Result<(), Error> {
+    let mut offset = 0;
+    let mut normal_offset = 0;
+
+    for mesh in meshes {
+        writeln!(writer, "o {}", mesh.name)?;
+
+        for (i, v) in mesh.vertices.iter().enumerate() {
+            match mesh.vertex_colors.as_ref().and_then(|colors| colors.get(i)) {
+                Some(color) => {
+                    let (r, g, b, _) = color.to_rgba_components();
+                    writeln!(writer, "v {} {} {} {} {} {}", v.x, v.y, v.z, r, g, b)?;
+                }
+                None => writeln!(writer, "v {} {} {}", v.x, v.y, v.z)?,
+            }
+        }
+
+        // Normals are written one per vertex so a face corner uses the same index for both
+        let normals = mesh
+            .vertex_normals
+            .as_ref()
+            .filter(|normals| normals.len() == mesh.vertices.len());
+
+        for n in normals.into_iter().flatten() {
+            writeln!(writer, "vn {} {} {}", n.x, n.y, n.z)?;
+        }
+
+        for face in &mesh.faces {
+            let refs: Vec<String> = face
+                .iter()
+                .map(|v| match normals {
+                    Some(_) => format!("{}//{}", v + offset + 1, v + normal_offset + 1),
+                    None => format!("{}", v + offset + 1),
+                })
+                .collect();
+
+            writeln!(writer, "f {}", refs.join(" "))?;
+        }
+
+        for line in &mesh.lines {
+            let refs: Vec<String> = line.iter().map(|v| format!("{}", v + offset + 1)).collect();
+
+            writeln!(writer, "l {}", refs.join(" "))?;
+        }
+
+        offset += mesh.vertices.len();
+        normal_offset += normals.map_or(0, |normals| normals.len());
+    }
+
+    Ok(())
+}
+
+fn current(groups: &mut [Group]) -> &mut Group {
+    groups
+        .last_mut()
+        .expect("There is always at least one group.")
+}
+
+// Builds a mesh from the file-wide vertices listed in `used`, remapping the group's indices to match
+fn build(
+    group: &Group,
+    used: &[usize],
+    positions: &[Point3D],
+    colors: &[Option<Color>],
+    normal_of: &dyn Fn(usize) -> Option<Point3D>,
+) -> Mesh {
+    let mut remap = vec![usize::MAX; positions.len()];
+
+    for (new, &old) in used.iter().enumerate() {
+        remap[old] = new;
+    }
+
+    let reindex = |elements: &Vec<Vec<usize>>| -> Vec<Vec<usize>> {
+        elements
+            .iter()
+            .map(|e| e.iter().map(|&v| remap[v]).collect())
+            .collect()
+    };
+
+    let mut mesh = Mesh::new(
+        &group.name,
+        used.iter().map(|&v| positions[v]).collect(),
+        reindex(&group.faces),
+    )
+    .with_lines(reindex(&group.lines));
+
+    // Attributes are only kept when every vertex has one
+    if let Some(colors) = used.iter().map(|&v| colors[v]).collect::<Option<Vec<_>>>() {
+        mesh = mesh.with_vertex_colors(colors);
+    }
+
+    if let Some(normals) = used
+        .iter()
+        .map(|&v| normal_of(v))
+        .collect::<Option<Vec<_>>>()
+    {
+        mesh = mesh.with_vertex_normals(normals);
+    }
+
+    mesh
+}
+
+fn parse_floats<'a>(tokens: impl Iterator<Item = &'a str>, line: usize) -> Result<Vec<f32>, Error> {
+    tokens
+        .map(|token| {
+            token
+                .parse::<f32>()
+                .map_err(|_| Error::parse(line, format!("invalid number '{token}'")))
+        })
+        .collect()
+}
+
+// OBJ indices are 1-based, negative ones count back from the most recently defined element
+fn resolve(token: Option<&str>, count: usize, line: usize) -> Result<usize, Error> {
+    let token = token.unwrap_or_default();
+    let index: i64 = token
+        .parse()
+        .map_err(|_| Error::parse(line, format!("invalid index '{token}'")))?;
+
+    let resolved = match index {
+        1.. => index - 1,
+        ..=-1 => count as i64 + index,
+        0 => -1,
+    };
+
+    match usize::try_from(resolved) {
+        Ok(resolved) if resolved < count => Ok(resolved),
+        _ => Err(Error::parse(line, format!("index {index} is out of range"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Result<Vec<Mesh>, Error> {
+        read(source.as_bytes())
+    }
+
+    fn parse_error(source: &str) -> (usize, String) {
+        match parse(source) {
+            Err(Error::Parse { line, message }) => (line, message),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_faces_and_lines() {
+        let meshes = parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\nf 1 2 3\nl 3 4\n").unwrap();
+
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes[0].vertices.len(), 4);
+        assert_eq!(meshes[0].vertices[1], Point3D::new(1.0, 0.0, 0.0));
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2]]);
+        assert_eq!(meshes[0].lines, vec![vec![2, 3]]);
+    }
+
+    #[test]
+    fn resolves_negative_indices_relative_to_the_last_vertex() {
+        let meshes = parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf -3 -2 -1\nv 0 0 1\nf 2 3 -1\n").unwrap();
+
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn reads_texture_and_normal_references() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\nf 1/1/1 2//1 3/1\n";
+        let meshes = parse(source).unwrap();
+        let normals = meshes[0].vertex_normals.as_ref();
+
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2]]);
+        // The third corner has no normal so the attribute is dropped for the whole mesh
+        assert!(normals.is_none());
+    }
+
+    #[test]
+    fn reads_vertex_colors() {
+        let meshes = parse("v 0 0 0 1 0 0\nv 1 0 0 0 1 0\nv 0 1 0 0 0 1\nf 1 2 3\n").unwrap();
+        let colors = meshes[0].vertex_colors.as_ref().unwrap();
+
+        assert_eq!(colors[1].to_rgba_components(), (0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn ignores_comments_and_unknown_statements() {
+        let source = "# header\nmtllib a.mtl\nv 0 0 0 # origin\nv 1 0 0\nv 0 1 0\ns off\nf 1 2 3\n";
+
+        assert_eq!(parse(source).unwrap()[0].faces.len(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_input_with_the_line_number() {
+        assert_eq!(parse_error("v 0 0\n").0, 1);
+        assert_eq!(
+            parse_error("v 0 0 0\nv 0 x 0\n"),
+            (2, "invalid number 'x'".to_owned())
+        );
+        assert_eq!(
+            parse_error("v 0 0 0\nf 1 1\n"),
+            (2, "face needs at least three vertices".to_owned())
+        );
+        assert_eq!(
+            parse_error("v 0 0 0\nl 1\n"),
+            (2, "line needs at least two vertices".to_owned())
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_indices() {
+        let vertices = "v 0 0 0\nv 1 0 0\nv 0 1 0\n";
+
+        assert_eq!(
+            parse_error(&format!("{vertices}f 1 2 4\n")),
+            (4, "index 4 is out of range".to_owned())
+        );
+        assert_eq!(
+            parse_error(&format!("{vertices}f 0 1 2\n")).1,
+            "index 0 is out of range"
+        );
+        assert_eq!(
+            parse_error(&format!("{vertices}f -4 1 2\n")).1,
+            "index -4 is out of range"
+        );
+        assert_eq!(
+            parse_error(&format!("{vertices}f 1/1 2/1 3/1\n")).1,
+            "index 1 is out of range"
+        );
+        assert_eq!(
+            parse_error(&format!("{vertices}f 1 2 a\n")).1,
+            "invalid index 'a'"
+        );
+    }
+
+    #[test]
+    fn splits_groups_into_meshes() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\no first\nf 1 2 3\ng second\nf 1 3 4\n";
+        let meshes = parse(source).unwrap();
+
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(meshes[0].name, "first");
+        assert_eq!(meshes[0].vertices.len(), 3);
+        assert_eq!(meshes[1].name, "second");
+        assert_eq!(meshes[1].faces, vec![vec![0, 1, 2]]);
+        assert_eq!(meshes[1].vertices[2], Point3D::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn round_trips_groups_with_unused_vertices() {
+        let first = Mesh::new(
+            "first",
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(0.0, 1.0, 0.0),
+                Point3D::new(5.0, 5.0, 5.0),
+            ],
+            vec![vec![0, 1, 2]],
+        );
+        let second = Mesh::new(
+            "second",
+            vec![
+                Point3D::new(-1.0, 0.0, 0.0),
+                Point3D::new(0.0, 0.0, 1.0),
+                Point3D::new(0.0, -1.0, 0.0),
+            ],
+            vec![vec![1, 2]],
+        );
+
+        let mut buffer = Vec::new();
+        write(&mut buffer, &[first.clone(), second.clone()]).unwrap();
+        let meshes = read(buffer.as_slice()).unwrap();
+
+        assert_eq!(meshes.len(), 2);
+
+        for (read, written) in meshes.iter().zip([first, second]) {
+            assert_eq!(read.name, written.name);
+            assert_eq!(read.vertices, written.vertices);
+            assert_eq!(read.faces, written.faces);
+            assert_eq!(read.lines, written.lines);
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use flo_canvas::Color;
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Copy)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+enum Kind {
+    Scalar(Scalar),
+    List { count: Scalar, item: Scalar },
+}
+
+struct Property {
+    name: String,
+    kind: Kind,
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+// One row of an element, list properties are flattened into `lists`
+#[derive(Default)]
+struct Row {
+    values: Vec<(String, f64, Scalar)>,
+    lists: Vec<(String, Vec<f64>)>,
+}
+
+impl Row {
+    fn get(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(n, _, _)| n == name)
+            .map(|(_, v, _)| *v)
+    }
+
+    // Integer channels are 0-255, floating point ones are already 0-1
+    fn channel(&self, name: &str) -> Option<f32> {
+        let (_, value, scalar) = self.values.iter().find(|(n, _, _)| n == name)?;
+
+        Some(match scalar {
+            Scalar::F32 | Scalar::F64 => *value as f32,
+            _ => *value as f32 / 255.0,
+        })
+    }
+
+    fn color(&self) -> Option<Color> {
+        Some(Color::Rgba(
+            self.channel("red")?,
+            self.channel("green")?,
+            self.channel("blue")?,
+            self.channel("alpha").unwrap_or(1.0),
+        ))
+    }
+
+    fn list(&self, names: &[&str]) -> Option<&Vec<f64>> {
+        self.lists
+            .iter()
+            .find(|(n, _)| names.contains(&n.as_str()))
+            .map(|(_, v)| v)
+    }
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Mesh>, Error> {
+    let path = path.as_ref();
+    let mut mesh = read(&fs::read(path)?)?;
+
+    if let Some(stem) = path.file_stem() {
+        mesh.name = stem.to_string_lossy().into_owned();
+    }
+
+    Ok(vec![mesh])
+}
+
+// Reads vertices (with optional normals and colors), faces, and edges which become lines with optional colors
+pub fn read(bytes: &[u8]) -> Result<Mesh, Error> {
+    let (format, elements, body) = read_header(bytes)?;
+    let mut source = match format {
+        Format::Ascii => Source::Ascii(
+            std::str::from_utf8(body)
+                .map_err(|_| Error::parse(0, "ASCII PLY body is not valid UTF-8"))?
+                .split_whitespace(),
+        ),
+        _ => Source::Binary {
+            bytes: body,
+            offset: 0,
+            big_endian: format == Format::BinaryBigEndian,
+        },
+    };
+
+    let mut vertices = Vec::new();
+    let mut normals = Vec::new();
+    let mut colors = Vec::new();
+    let mut faces = Vec::new();
+    let mut lines = Vec::new();
+    let mut edge_colors = Vec::new();
+
+    for element in &elements {
+        for _ in 0..element.count {
+            let row = source.row(element)?;
+
+            match element.name.as_str() {
+                "vertex" => {
+                    let (Some(x), Some(y), Some(z)) = (row.get("x"), row.get("y"), row.get("z"))
+                    else {
+                        return Err(Error::parse(0, "vertex is missing x, y or z"));
+                    };
+
+                    vertices.push(Point3D::new(x as f32, y as f32, z as f32));
+                    normals.push(match (row.get("nx"), row.get("ny"), row.get("nz")) {
+                        (Some(x), Some(y), Some(z)) => {
+                            Some(Point3D::new(x as f32, y as f32, z as f32))
+                        }
+                        _ => None,
+                    });
+                    colors.push(row.color());
+                }
+                "face" => {
+                    let indices = row
+                        .list(&["vertex_indices", "vertex_index"])
+                        .ok_or_else(|| Error::parse(0, "face has no vertex_indices"))?;
+
+                    faces.push(
+                        indices
+                            .iter()
+                            .map(|&i| index(i))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    );
+                }
+                "edge" => {
+                    let (Some(a), Some(b)) = (row.get("vertex1"), row.get("vertex2")) else {
+                        return Err(Error::parse(0, "edge is missing vertex1 or vertex2"));
+                    };
+
+                    lines.push(vec![index(a)?, index(b)?]);
+                    edge_colors.push(row.color());
+                }
+                // Unknown elements still have to be read to get past them
+                _ => {}
+            }
+        }
+    }
+
+    let mut mesh = Mesh::new("", vertices, faces).with_lines(lines.clone());
+
+    if let Some(colors) = colors.into_iter().collect::<Option<Vec<_>>>() {
+        mesh = mesh.with_vertex_colors(colors);
+    }
+
+    if let Some(normals) = normals.into_iter().collect::<Option<Vec<_>>>() {
+        mesh = mesh.with_vertex_normals(normals);
+    }
+
+    for (line, color) in lines.iter().zip(edge_colors) {
+        if let Some(color) = color {
+            mesh.set_edge_color(line[0], line[1], color);
+        }
+    }
+
+    Ok(mesh)
+}
+
+fn read_header(bytes: &[u8]) -> Result<(Format, Vec<Element>, &[u8]), Error> {
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    let mut offset = 0;
+
+    for number in 1.. {
+        let end = bytes[offset..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or_else(|| Error::parse(number, "header has no end_header"))?;
+        let line = String::from_utf8_lossy(&bytes[offset..offset + end]);
+        let mut tokens = line.split_whitespace();
+
+        offset += end + 1;
+
+        match (number, tokens.next()) {
+            (1, Some("ply")) => {}
+            (1, _) => return Err(Error::parse(number, "missing 'ply' magic")),
+            (_, Some("format")) => {
+                format = Some(match tokens.next() {
+                    Some("ascii") => Format::Ascii,
+                    Some("binary_little_endian") => Format::BinaryLittleEndian,
+                    Some("binary_big_endian") => Format::BinaryBigEndian,
+                    other => {
+                        return Err(Error::Unsupported(format!(
+                            "PLY format {}",
+                            other.unwrap_or_default()
+                        )));
+                    }
+                });
+            }
+            (_, Some("element")) => {
+                let name = tokens.next().unwrap_or_default().to_owned();
+                let count = tokens
+                    .next()
+                    .and_then(|c| c.parse().ok())
+                    .ok_or_else(|| Error::parse(number, "element needs a count"))?;
+
+                elements.push(Element {
+                    name,
+                    count,
+                    properties: Vec::new(),
+                });
+            }
+            (_, Some("property")) => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| Error::parse(number, "property before any element"))?;
+                let kind = match tokens.next() {
+                    Some("list") => Kind::List {
+                        count: scalar(tokens.next(), number)?,
+                        item: scalar(tokens.next(), number)?,
+                    },
+                    ty => Kind::Scalar(scalar(ty, number)?),
+                };
+                let name = tokens.next().unwrap_or_default().to_owned();
+
+                element.properties.push(Property { name, kind });
+            }
+            (_, Some("end_header")) => break,
+            // comment, obj_info and blank lines
+            _ => {}
+        }
+    }
+
+    let format = format.ok_or_else(|| Error::parse(0, "header has no format"))?;
+
+    Ok((format, elements, &bytes[offset..]))
+}
+
+// Indices past the end are left for validation, negative ones would silently wrap to 0 so they're rejected here
+fn index(value: f64) -> Result<usize, Error> {
+    match value {
+        0.0.. => Ok(value as usize),
+        _ => Err(Error::parse(0, format!("negative vertex index {value}"))),
+    }
+}
+
+fn scalar(name: Option<&str>, line: usize) -> Result<Scalar, Error> {
+    Ok(match name.unwrap_or_default() {
+        "char" | "int8" => Scalar::I8,
+        "uchar" | "uint8" => Scalar::U8,
+        "short" | "int16" => Scalar::I16,
+        "ushort" | "uint16" => Scalar::U16,
+        "int" | "int32" => Scalar::I32,
+        "uint" | "uint32" => Scalar::U32,
+        "float" | "float32" => Scalar::F32,
+        "double" | "float64" => Scalar::F64,
+        other => {
+            return Err(Error::parse(
+                line,
+                format!("unknown property type '{other}'"),
+            ));
+        }
+    })
+}
+
+enum Source<'a> {
+    Ascii(std::str::SplitWhitespace<'a>),
+    Binary {
+        bytes: &'a [u8],
+        offset: usize,
+        big_endian: bool,
+    },
+}
+
+impl Source<'_> {
+    fn row(&mut self, element: &Element) -> Result<Row, Error> {
+        let mut row = Row::default();
+
+        for property in &element.properties {
+            match property.kind {
+                Kind::Scalar(scalar) => {
+                    let value = self.value(scalar)?;
+                    row.values.push((property.name.clone(), value, scalar));
+                }
+                Kind::List { count, item } => {
+                    let count = self.value(count)? as usize;
+                    let values = (0..count)
+                        .map(|_| self.value(item))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    row.lists.push((property.name.clone(), values));
+                }
+            }
+        }
+
+        Ok(row)
+    }
+
+    fn value(&mut self, scalar: Scalar) -> Result<f64, Error> {
+        match self {
+            Source::Ascii(tokens) => {
+                let token = tokens
+                    .next()
+                    .ok_or_else(|| Error::parse(0, "unexpected end of PLY data"))?;
+
+                token
+                    .parse()
+                    .map_err(|_| Error::parse(0, format!("invalid number '{token}'")))
+            }
+            Source::Binary {
+                bytes,
+                offset,
+                big_endian,
+            } => {
+                let size = match scalar {
+                    Scalar::I8 | Scalar::U8 => 1,
+                    Scalar::I16 | Scalar::U16 => 2,
+                    Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+                    Scalar::F64 => 8,
+                };
+                let mut raw = bytes
+                    .get(*offset..*offset + size)
+                    .ok_or_else(|| Error::parse(0, "unexpected end of PLY data"))?
+                    .to_vec();
+
+                *offset += size;
+
+                // Decode everything as little endian
+                if *big_endian {
+                    raw.reverse();
+                }
+
+                Ok(match scalar {
+                    Scalar::I8 => raw[0] as i8 as f64,
+                    Scalar::U8 => raw[0] as f64,
+                    Scalar::I16 => i16::from_le_bytes([raw[0], raw[1]]) as f64,
+                    Scalar::U16 => u16::from_le_bytes([raw[0], raw[1]]) as f64,
+                    Scalar::I32 => i32::from_le_bytes(raw[..4].try_into().unwrap()) as f64,
+                    Scalar::U32 => u32::from_le_bytes(raw[..4].try_into().unwrap()) as f64,
+                    Scalar::F32 => f32::from_le_bytes(raw[..4].try_into().unwrap()) as f64,
+                    Scalar::F64 => f64::from_le_bytes(raw[..8].try_into().unwrap()),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ASCII: &str = "ply
+format ascii 1.0
+comment a colored triangle with one colored edge
+element vertex 3
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+element edge 1
+property int vertex1
+property int vertex2
+property uchar red
+property uchar green
+property uchar blue
+end_header
+0 0 0 255 0 0
+1 0 0 0 255 0
+0 1 0 0 0 255
+3 0 1 2
+0 2 255 255 255
+";
+
+    // A quad as two triangles with float positions and int indices, encoded with `encode`
+    fn binary(format: &str, encode: fn(&[u8]) -> Vec<u8>) -> Vec<u8> {
+        let mut bytes = format!(
+            "ply\nformat {format} 1.0\nelement vertex 4\nproperty float x\nproperty float y\nproperty float z\n\
+             element face 2\nproperty list uchar int vertex_indices\nend_header\n"
+        )
+        .into_bytes();
+
+        for [x, y] in [[0.0f32, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]] {
+            for c in [x, y, 0.0] {
+                bytes.extend(encode(&c.to_le_bytes()));
+            }
+        }
+
+        for face in [[0i32, 1, 2], [0, 2, 3]] {
+            bytes.push(3);
+
+            for i in face {
+                bytes.extend(encode(&i.to_le_bytes()));
+            }
+        }
+
+        bytes
+    }
+
+    fn parse_error(bytes: &[u8]) -> (usize, String) {
+        match read(bytes) {
+            Err(Error::Parse { line, message }) => (line, message),
+            other => panic!("expected a parse error, got {:?}", other.map(|m| m.faces)),
+        }
+    }
+
+    #[test]
+    fn reads_ascii_with_vertex_and_edge_colors() {
+        let mesh = read(ASCII.as_bytes()).unwrap();
+        let colors = mesh.vertex_colors.as_ref().unwrap();
+        let edge_colors = mesh.edge_colors.as_ref().unwrap();
+
+        assert_eq!(mesh.vertices[2], Point3D::new(0.0, 1.0, 0.0));
+        assert_eq!(mesh.faces, vec![vec![0, 1, 2]]);
+        assert_eq!(mesh.lines, vec![vec![0, 2]]);
+        assert_eq!(colors[1].to_rgba_components(), (0.0, 1.0, 0.0, 1.0));
+        assert_eq!(
+            edge_colors[&(0, 2)].to_rgba_components(),
+            (1.0, 1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn reads_binary_in_either_byte_order() {
+        let little = read(&binary("binary_little_endian", |b| b.to_vec())).unwrap();
+        let big = read(&binary("binary_big_endian", |b| {
+            b.iter().rev().copied().collect()
+        }))
+        .unwrap();
+
+        for mesh in [little, big] {
+            assert_eq!(mesh.vertices[2], Point3D::new(1.0, 1.0, 0.0));
+            assert_eq!(mesh.faces, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+        }
+    }
+
+    #[test]
+    fn skips_unknown_elements() {
+        let source = ASCII
+            .replace(
+                "element face 1",
+                "element material 2\nproperty float shine\nproperty list uchar float weights\nelement face 1",
+            )
+            .replace("3 0 1 2\n", "0.5 2 1 1\n0.25 0\n3 0 1 2\n");
+
+        assert_eq!(read(source.as_bytes()).unwrap().faces, vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn rejects_negative_indices() {
+        assert_eq!(
+            parse_error(ASCII.replace("3 0 1 2", "3 0 -1 2").as_bytes()).1,
+            "negative vertex index -1"
+        );
+        assert_eq!(
+            parse_error(ASCII.replace("0 2 255", "-2 2 255").as_bytes()).1,
+            "negative vertex index -2"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert_eq!(
+            parse_error(ASCII.replacen("ply", "obj", 1).as_bytes()),
+            (1, "missing 'ply' magic".to_owned())
+        );
+        assert_eq!(
+            parse_error(ASCII.replace("format ascii 1.0\n", "").as_bytes()).1,
+            "header has no format"
+        );
+        assert_eq!(
+            parse_error(
+                ASCII
+                    .replace("property float y", "property half y")
+                    .as_bytes()
+            ),
+            (6, "unknown property type 'half'".to_owned())
+        );
+        assert_eq!(
+            parse_error(ASCII.replace("end_header\n", "").as_bytes()).1,
+            "header has no end_header"
+        );
+        assert!(matches!(
+            read(ASCII.replace("ascii", "binary_middle_endian").as_bytes()),
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_bodies() {
+        assert_eq!(
+            parse_error(ASCII.replace("0 1 0 0 0 255", "0 x 0 0 0 255").as_bytes()).1,
+            "invalid number 'x'"
+        );
+        assert_eq!(
+            parse_error(ASCII.replace("0 2 255 255 255\n", "").as_bytes()).1,
+            "unexpected end of PLY data"
+        );
+
+        let truncated = binary("binary_little_endian", |b| b.to_vec());
+
+        assert_eq!(
+            parse_error(&truncated[..truncated.len() - 1]).1,
+            "unexpected end of PLY data"
+        );
+    }
+}
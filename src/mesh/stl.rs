@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+const HEADER_SIZE: usize = 80;
+const TRIANGLE_SIZE: usize = 50;
+
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Mesh>, Error> {
+    let path = path.as_ref();
+    let mut meshes = read(&fs::read(path)?)?;
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    for mesh in meshes.iter_mut().filter(|m| m.name.is_empty()) {
+        mesh.name = stem.clone();
+    }
+
+    Ok(meshes)
+}
+
+// Binary files can also start with "solid", so the size check decides first
+pub fn read(bytes: &[u8]) -> Result<Vec<Mesh>, Error> {
+    if let Some(count) = binary_count(bytes)
+        && HEADER_SIZE + 4 + count * TRIANGLE_SIZE == bytes.len()
+    {
+        return Ok(vec![read_binary(bytes, count)]);
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.trim_start().starts_with("solid") => read_ascii(text),
+        _ => Err(Error::parse(0, "not a valid ASCII or binary STL file")),
+    }
+}
+
+fn binary_count(bytes: &[u8]) -> Option<usize> {
+    let count = bytes.get(HEADER_SIZE..HEADER_SIZE + 4)?;
+
+    Some(u32::from_le_bytes(count.try_into().ok()?) as usize)
+}
+
+fn read_binary(bytes: &[u8], count: usize) -> Mesh {
+    let mut welder = Welder::default();
+    let float = |offset: usize| f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+    for t in 0..count {
+        // Skip the 12 byte facet normal, it's recomputed from the winding when needed
+        let start = HEADER_SIZE + 4 + t * TRIANGLE_SIZE + 12;
+        let face = (0..3)
+            .map(|i| {
+                let v = start + i * 12;
+                welder.index(Point3D::new(float(v), float(v + 4), float(v + 8)))
+            })
+            .collect();
+
+        welder.faces.push(face);
+    }
+
+    welder.finish("")
+}
+
+fn read_ascii(text: &str) -> Result<Vec<Mesh>, Error> {
+    let mut meshes = Vec::new();
+    let mut welder = Welder::default();
+    let mut name = String::new();
+    let mut polygon: Vec<usize> = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let number = number + 1;
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("solid") => name = tokens.collect::<Vec<_>>().join(" "),
+            Some("vertex") => {
+                let values = tokens
+                    .map(|t| {
+                        t.parse::<f32>()
+                            .map_err(|_| Error::parse(number, format!("invalid number '{t}'")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if values.len() != 3 {
+                    return Err(Error::parse(number, "vertex needs x, y and z"));
+                }
+
+                polygon.push(welder.index(Point3D::new(values[0], values[1], values[2])));
+            }
+            Some("endloop") => {
+                if polygon.len() < 3 {
+                    return Err(Error::parse(number, "facet needs at least three vertices"));
+                }
+
+                welder.faces.push(std::mem::take(&mut polygon));
+            }
+            Some("endsolid") => meshes.push(std::mem::take(&mut welder).finish(&name)),
+            // facet normals, outer loop and endfacet carry nothing the wireframe needs
+            _ => {}
+        }
+    }
+
+    // Be lenient with files that are missing their final endsolid
+    if !welder.faces.is_empty() {
+        meshes.push(welder.finish(&name));
+    }
+
+    Ok(meshes)
+}
+
+// STL is a triangle soup, identical positions are merged so shared edges only exist once
+#[derive(Default)]
+struct Welder {
+    vertices: Vec<Point3D>,
+    faces: Vec<Vec<usize>>,
+    lookup: HashMap<[u32; 3], usize>,
+}
+
+impl Welder {
+    fn index(&mut self, p: Point3D) -> usize {
+        // -0.0 and 0.0 are the same position but have different bits
+        let key = [p.x, p.y, p.z].map(|c| if c == 0.0 { 0 } else { c.to_bits() });
+
+        *self.lookup.entry(key).or_insert_with(|| {
+            self.vertices.push(p);
+            self.vertices.len() - 1
+        })
+    }
+
+    fn finish(self, name: &str) -> Mesh {
+        Mesh::new(name, self.vertices, self.faces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWO_TRIANGLES: [[[f32; 3]; 3]; 2] = [
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]],
+    ];
+
+    fn ascii(name: &str, triangles: &[[[f32; 3]; 3]]) -> String {
+        let mut text = format!("solid {name}\n");
+
+        for triangle in triangles {
+            text += "  facet normal 0 0 1\n    outer loop\n";
+
+            for [x, y, z] in triangle {
+                text += &format!("      vertex {x} {y} {z}\n");
+            }
+
+            text += "    endloop\n  endfacet\n";
+        }
+
+        text + &format!("endsolid {name}\n")
+    }
+
+    fn binary(header: &[u8], triangles: &[[[f32; 3]; 3]]) -> Vec<u8> {
+        let mut bytes = header.to_vec();
+        bytes.resize(HEADER_SIZE, 0);
+        bytes.extend((triangles.len() as u32).to_le_bytes());
+
+        for triangle in triangles {
+            bytes.extend([0u8; 12]);
+
+            for c in triangle.iter().flatten() {
+                bytes.extend(c.to_le_bytes());
+            }
+
+            bytes.extend([0u8; 2]);
+        }
+
+        bytes
+    }
+
+    fn parse_error(bytes: &[u8]) -> (usize, String) {
+        match read(bytes) {
+            Err(Error::Parse { line, message }) => (line, message),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_ascii_and_welds_shared_vertices() {
+        let meshes = read(ascii("quad", &TWO_TRIANGLES).as_bytes()).unwrap();
+
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes[0].name, "quad");
+        assert_eq!(meshes[0].vertices.len(), 4);
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+    }
+
+    #[test]
+    fn reads_every_solid_in_an_ascii_file() {
+        let text = ascii("a", &TWO_TRIANGLES[..1]) + &ascii("b", &TWO_TRIANGLES[1..]);
+        let meshes = read(text.as_bytes()).unwrap();
+        let names: Vec<&str> = meshes.iter().map(|m| m.name.as_str()).collect();
+
+        assert_eq!(names, ["a", "b"]);
+        assert_eq!(meshes[1].vertices.len(), 3);
+    }
+
+    #[test]
+    fn keeps_a_solid_without_endsolid() {
+        let text = ascii("open", &TWO_TRIANGLES).replace("endsolid open\n", "");
+
+        assert_eq!(read(text.as_bytes()).unwrap()[0].faces.len(), 2);
+    }
+
+    #[test]
+    fn reads_binary_and_welds_shared_vertices() {
+        let meshes = read(&binary(b"binary", &TWO_TRIANGLES)).unwrap();
+
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes[0].vertices.len(), 4);
+        assert_eq!(meshes[0].faces, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+    }
+
+    #[test]
+    fn reads_binary_with_a_solid_header_by_its_size() {
+        let meshes = read(&binary(b"solid exported", &TWO_TRIANGLES)).unwrap();
+
+        assert_eq!(meshes[0].faces.len(), 2);
+    }
+
+    #[test]
+    fn welds_negative_zero() {
+        let triangles = [
+            [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            [[-0.0, 0.0, -0.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]],
+        ];
+
+        assert_eq!(read(&binary(b"", &triangles)).unwrap()[0].vertices.len(), 4);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let text = ascii("bad", &TWO_TRIANGLES);
+
+        assert_eq!(
+            parse_error(text.replacen("vertex 1 0 0", "vertex 1 x 0", 1).as_bytes()),
+            (5, "invalid number 'x'".to_owned())
+        );
+        assert_eq!(
+            parse_error(text.replacen("vertex 1 0 0", "vertex 1 0", 1).as_bytes()),
+            (5, "vertex needs x, y and z".to_owned())
+        );
+        assert_eq!(
+            parse_error(text.replacen("      vertex 1 0 0\n", "", 1).as_bytes()),
+            (6, "facet needs at least three vertices".to_owned())
+        );
+        // A binary file whose size doesn't match its triangle count
+        assert_eq!(parse_error(&binary(b"", &TWO_TRIANGLES)[..120]).0, 0);
+    }
+}
@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use flo_canvas::Color;
+
+use crate::mesh::{Edge, Mesh};
+use crate::point3d::Point3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scheme {
+    // Triangles only, polygons are fanned into triangles first
+    Loop,
+    // Any polygons, every level after the first is all quads
+    CatmullClark,
+}
+
+impl Scheme {
+    // Loop for all-triangle meshes, Catmull-Clark for anything with larger polygons
+    pub fn for_mesh(mesh: &Mesh) -> Self {
+        match mesh.faces.iter().all(|f| f.len() == 3) {
+            true => Scheme::Loop,
+            false => Scheme::CatmullClark,
+        }
+    }
+}
+
+// Each new vertex as a weighted sum of the vertices one level up
+type Stencil = Vec<Vec<(usize, f32)>>;
+
+// Every smoothing rule is a fixed blend of neighbouring vertices, so the weights only depend on the topology
+// Keeping them lets a cage whose vertices move but whose faces don't (like an animated plot) skip rebuilding it
+pub struct Subdivision {
+    stencils: Vec<Stencil>,
+}
+
+impl Subdivision {
+    // Edges on fewer or more than two faces are creases, they're smoothed along their own length only
+    // Lines are kept pointing at their original vertices, normals and edge colors are dropped since they no longer apply
+    pub fn new(mesh: &Mesh, scheme: Scheme, levels: usize) -> (Mesh, Self) {
+        // Repeated corners don't enclose anything and have no edge between them, so they're removed up front
+        let faces = mesh.faces.iter().filter_map(|f| {
+            let mut face: Vec<usize> = f.clone();
+
+            face.dedup();
+
+            while face.len() > 1 && face.first() == face.last() {
+                face.pop();
+            }
+
+            (face.len() >= 3).then_some(face)
+        });
+
+        let mut mesh = Mesh {
+            faces: match scheme {
+                Scheme::Loop => faces
+                    .flat_map(|f| (1..f.len() - 1).map(move |i| vec![f[0], f[i], f[i + 1]]))
+                    .collect(),
+                Scheme::CatmullClark => faces.collect(),
+            },
+            vertex_normals: None,
+            edge_colors: None,
+            ..mesh.clone()
+        };
+        let mut stencils = Vec::new();
+
+        for _ in 0..levels {
+            let (next, stencil) = match scheme {
+                Scheme::Loop => loop_step(&mesh),
+                Scheme::CatmullClark => catmull_clark_step(&mesh),
+            };
+
+            mesh = next;
+            stencils.push(stencil);
+        }
+
+        (mesh, Self { stencils })
+    }
+
+    // Vertices of the subdivided mesh for a new set of positions for the cage's vertices
+    pub fn apply(&self, vertices: &[Point3D]) -> Vec<Point3D> {
+        self.stencils
+            .iter()
+            .fold(vertices.to_vec(), |vertices, stencil| {
+                blend(stencil, &vertices)
+            })
+    }
+}
+
+pub fn subdivide(mesh: &Mesh, scheme: Scheme, levels: usize) -> Mesh {
+    Subdivision::new(mesh, scheme, levels).0
+}
+
+fn blend(stencil: &Stencil, vertices: &[Point3D]) -> Vec<Point3D> {
+    stencil
+        .iter()
+        .map(|weights| {
+            weights
+                .iter()
+                .fold(Point3D::default(), |sum, &(v, w)| sum + vertices[v] * w)
+        })
+        .collect()
+}
+
+// What the smoothing rules need to know about the neighbourhood of each vertex
+struct Topology {
+    edges: Vec<Edge>,
+    lookup: HashMap<(usize, usize), usize>,
+    // Every neighbour across a face edge
+    neighbours: Vec<Vec<usize>>,
+    // Neighbours across crease edges, a vertex with exactly two follows its crease and any other count is a corner
+    creases: Vec<Vec<usize>>,
+    faces: Vec<Vec<usize>>,
+}
+
+impl Topology {
+    fn new(mesh: &Mesh) -> Self {
+        let edges: Vec<Edge> = Mesh {
+            lines: Vec::new(),
+            ..mesh.clone()
+        }
+        .edges();
+
+        let mut lookup = HashMap::new();
+        let mut neighbours = vec![Vec::new(); mesh.vertices.len()];
+        let mut creases = vec![Vec::new(); mesh.vertices.len()];
+        let mut faces = vec![Vec::new(); mesh.vertices.len()];
+
+        for (i, edge) in edges.iter().enumerate() {
+            lookup.insert((edge.a, edge.b), i);
+            neighbours[edge.a].push(edge.b);
+            neighbours[edge.b].push(edge.a);
+
+            if edge.faces.len() != 2 {
+                creases[edge.a].push(edge.b);
+                creases[edge.b].push(edge.a);
+            }
+        }
+
+        for (i, face) in mesh.faces.iter().enumerate() {
+            for &v in face {
+                faces[v].push(i);
+            }
+        }
+
+        Self {
+            edges,
+            lookup,
+            neighbours,
+            creases,
+            faces,
+        }
+    }
+
+    fn edge(&self, a: usize, b: usize) -> usize {
+        self.lookup[&Mesh::edge_key(a, b)]
+    }
+
+    // Boundaries and creases keep their curve, 1/8 3/4 1/8 along it, corners don't move
+    fn crease_weights(&self, v: usize) -> Option<Vec<(usize, f32)>> {
+        match self.creases[v].as_slice() {
+            [] => None,
+            &[a, b] => Some(vec![(v, 0.75), (a, 0.125), (b, 0.125)]),
+            _ => Some(vec![(v, 1.0)]),
+        }
+    }
+}
+
+fn loop_step(mesh: &Mesh) -> (Mesh, Stencil) {
+    let topology = Topology::new(mesh);
+    let offset = mesh.vertices.len();
+
+    let mut stencil: Stencil = (0..mesh.vertices.len())
+        .map(|v| {
+            let neighbours = &topology.neighbours[v];
+
+            if neighbours.is_empty() {
+                return vec![(v, 1.0)];
+            }
+
+            topology.crease_weights(v).unwrap_or_else(|| {
+                // Loop's original weights
+                let n = neighbours.len() as f32;
+                let beta = (0.625 - (0.375 + 0.25 * f32::cos(TAU / n)).powi(2)) / n;
+
+                std::iter::once((v, 1.0 - n * beta))
+                    .chain(neighbours.iter().map(|&u| (u, beta)))
+                    .collect()
+            })
+        })
+        .collect();
+
+    stencil.extend(topology.edges.iter().map(|edge| {
+        let ends = [(edge.a, 0.5), (edge.b, 0.5)];
+
+        match edge.faces.as_slice() {
+            &[f, g] => {
+                let opposite = |face: usize| {
+                    mesh.faces[face]
+                        .iter()
+                        .find(|&&v| v != edge.a && v != edge.b)
+                        .map(|&v| (v, 0.125))
+                };
+
+                [(edge.a, 0.375), (edge.b, 0.375)]
+                    .into_iter()
+                    .chain(opposite(f))
+                    .chain(opposite(g))
+                    .collect()
+            }
+            _ => ends.to_vec(),
+        }
+    }));
+
+    let mid = |a: usize, b: usize| offset + topology.edge(a, b);
+    let faces = mesh
+        .faces
+        .iter()
+        .flat_map(|f| {
+            let [a, b, c] = [f[0], f[1], f[2]];
+            let (ab, bc, ca) = (mid(a, b), mid(b, c), mid(c, a));
+
+            [
+                vec![a, ab, ca],
+                vec![b, bc, ab],
+                vec![c, ca, bc],
+                vec![ab, bc, ca],
+            ]
+        })
+        .collect();
+
+    let subdivided = Mesh {
+        vertices: blend(&stencil, &mesh.vertices),
+        faces,
+        vertex_colors: blend_colors(mesh, &topology, false),
+        ..mesh.clone()
+    };
+
+    (subdivided, stencil)
+}
+
+fn catmull_clark_step(mesh: &Mesh) -> (Mesh, Stencil) {
+    let topology = Topology::new(mesh);
+    let edge_offset = mesh.vertices.len();
+    let face_offset = edge_offset + topology.edges.len();
+
+    // Face points are the average of their corners, scaled so they can be mixed into the other rules
+    let face_point = |f: usize, scale: f32| {
+        let face = &mesh.faces[f];
+
+        face.iter().map(move |&v| (v, scale / face.len() as f32))
+    };
+
+    let edge_points = topology
+        .edges
+        .iter()
+        .map(|edge| match edge.faces.as_slice() {
+            &[f, g] => [(edge.a, 0.25), (edge.b, 0.25)]
+                .into_iter()
+                .chain(face_point(f, 0.25))
+                .chain(face_point(g, 0.25))
+                .collect(),
+            _ => vec![(edge.a, 0.5), (edge.b, 0.5)],
+        });
+
+    let mut stencil: Stencil = (0..mesh.vertices.len())
+        .map(|v| {
+            let neighbours = &topology.neighbours[v];
+
+            if neighbours.is_empty() {
+                return vec![(v, 1.0)];
+            }
+
+            topology.crease_weights(v).unwrap_or_else(|| {
+                // (F + 2R + (n - 3)P) / n with F the average face point and R the average edge midpoint
+                // R is half P and half the average neighbour, which leaves (n - 2) / n on P itself
+                let n = neighbours.len() as f32;
+                let faces = &topology.faces[v];
+                let face_weight = 1.0 / (n * faces.len().max(1) as f32);
+
+                std::iter::once((v, (n - 2.0) / n))
+                    .chain(neighbours.iter().map(|&u| (u, 1.0 / (n * n))))
+                    .chain(faces.iter().flat_map(|&f| face_point(f, face_weight)))
+                    .collect()
+            })
+        })
+        .collect();
+
+    stencil.extend(edge_points);
+    stencil.extend((0..mesh.faces.len()).map(|f| face_point(f, 1.0).collect()));
+
+    let mid = |a: usize, b: usize| edge_offset + topology.edge(a, b);
+    let faces = mesh
+        .faces
+        .iter()
+        .enumerate()
+        .flat_map(|(i, f)| {
+            let n = f.len();
+
+            (0..n).map(move |j| {
+                let (previous, current, next) = (f[(j + n - 1) % n], f[j], f[(j + 1) % n]);
+
+                vec![
+                    current,
+                    mid(current, next),
+                    face_offset + i,
+                    mid(previous, current),
+                ]
+            })
+        })
+        .collect();
+
+    let subdivided = Mesh {
+        vertices: blend(&stencil, &mesh.vertices),
+        faces,
+        vertex_colors: blend_colors(mesh, &topology, true),
+        ..mesh.clone()
+    };
+
+    (subdivided, stencil)
+}
+
+// New vertices blend the colors around them, edge points from their ends and face points from their corners
+fn blend_colors(mesh: &Mesh, topology: &Topology, face_points: bool) -> Option<Vec<Color>> {
+    let colors = mesh.vertex_colors.as_ref()?;
+    let average = |vertices: &[usize]| {
+        let (r, g, b, a) = vertices
+            .iter()
+            .fold((0.0, 0.0, 0.0, 0.0), |(r, g, b, a), &v| {
+                let (cr, cg, cb, ca) = colors[v].to_rgba_components();
+
+                (r + cr, g + cg, b + cb, a + ca)
+            });
+        let n = vertices.len().max(1) as f32;
+
+        Color::Rgba(r / n, g / n, b / n, a / n)
+    };
+
+    let mut blended = colors.clone();
+    blended.extend(topology.edges.iter().map(|edge| average(&[edge.a, edge.b])));
+    if face_points {
+        blended.extend(mesh.faces.iter().map(|f| average(f)));
+    }
+
+    Some(blended)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Shape;
+    use crate::shape::cube::Cube;
+    use crate::shape::platonic::Tetrahedron;
+
+    #[test]
+    fn skips_repeated_corners() {
+        let vertices = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+        ];
+        let mesh = Mesh::new(
+            "repeated",
+            vertices,
+            vec![
+                vec![0, 1, 2],
+                vec![1, 3, 2],
+                vec![1, 1, 3],
+                vec![2, 3, 3, 2],
+            ],
+        );
+
+        assert!(mesh.validate().is_valid());
+
+        for scheme in [Scheme::Loop, Scheme::CatmullClark] {
+            assert!(subdivide(&mesh, scheme, 2).validate().is_valid());
+        }
+    }
+
+    #[test]
+    fn loop_splits_each_triangle_in_four() {
+        let tetrahedron = Tetrahedron::mesh();
+        let subdivided = subdivide(&tetrahedron, Scheme::Loop, 1);
+
+        // One new vertex per edge
+        assert_eq!(subdivided.vertices.len(), 4 + 6);
+        assert_eq!(subdivided.faces.len(), 16);
+        assert!(subdivided.faces.iter().all(|f| f.len() == 3));
+        assert_eq!(subdivided.validate().issues, vec![]);
+    }
+
+    #[test]
+    fn catmull_clark_splits_each_face_into_quads() {
+        let cube = Cube::mesh();
+        let subdivided = subdivide(&cube, Scheme::CatmullClark, 1);
+
+        assert_eq!(cube.validate().issues, vec![]);
+        // One new vertex per edge and per face
+        assert_eq!(subdivided.vertices.len(), 8 + 12 + 6);
+        assert_eq!(subdivided.faces.len(), 24);
+        assert!(subdivided.faces.iter().all(|f| f.len() == 4));
+        assert_eq!(subdivided.validate().issues, vec![]);
+    }
+
+    #[test]
+    fn applying_the_stencils_matches_a_rebuild() {
+        let cube = Cube::mesh();
+        let (_, subdivision) = Subdivision::new(&cube, Scheme::CatmullClark, 2);
+
+        let mut moved = cube.clone();
+        for (i, v) in moved.vertices.iter_mut().enumerate() {
+            *v = *v * (1.0 + i as f32 * 0.1) + Point3D::new(0.0, i as f32 * 0.05, 0.0);
+        }
+
+        let rebuilt = subdivide(&moved, Scheme::CatmullClark, 2);
+        let applied = subdivision.apply(&moved.vertices);
+
+        assert_eq!(applied.len(), rebuilt.vertices.len());
+        assert!(
+            applied
+                .iter()
+                .zip(&rebuilt.vertices)
+                .all(|(a, b)| a.approx_eq(b, 1e-5))
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Which element an index belongs to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Element {
+    Face(usize),
+    Line(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    // Errors, rendering a mesh with any of these would panic or draw garbage
+    IndexOutOfRange {
+        element: Element,
+        index: usize,
+    },
+    NonFiniteVertex {
+        vertex: usize,
+    },
+    AttributeCount {
+        attribute: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    // Warnings, the mesh renders but probably isn't what the author intended
+    DegenerateFace {
+        face: usize,
+    },
+    DuplicateFace {
+        face: usize,
+        original: usize,
+    },
+    UnusedVertex {
+        vertex: usize,
+    },
+    NonManifoldEdge {
+        a: usize,
+        b: usize,
+        faces: usize,
+    },
+    InconsistentWinding {
+        a: usize,
+        b: usize,
+        faces: (usize, usize),
+    },
+}
+
+impl Issue {
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            Issue::IndexOutOfRange { .. }
+                | Issue::NonFiniteVertex { .. }
+                | Issue::AttributeCount { .. }
+        )
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::IndexOutOfRange {
+                element: Element::Face(face),
+                index,
+            } => write!(f, "face {face} references missing vertex {index}"),
+            Issue::IndexOutOfRange {
+                element: Element::Line(line),
+                index,
+            } => write!(f, "line {line} references missing vertex {index}"),
+            Issue::NonFiniteVertex { vertex } => {
+                write!(f, "vertex {vertex} has a NaN or infinite coordinate")
+            }
+            Issue::AttributeCount {
+                attribute,
+                expected,
+                found,
+            } => write!(f, "expected {expected} {attribute} but found {found}"),
+            Issue::DegenerateFace { face } => write!(f, "face {face} has no area"),
+            Issue::DuplicateFace { face, original } => {
+                write!(f, "face {face} duplicates face {original}")
+            }
+            Issue::UnusedVertex { vertex } => {
+                write!(f, "vertex {vertex} isn't used by any face or line")
+            }
+            Issue::NonManifoldEdge { a, b, faces } => {
+                write!(f, "edge {a}-{b} is shared by {faces} faces")
+            }
+            Issue::InconsistentWinding { a, b, faces } => write!(
+                f,
+                "faces {} and {} both run along edge {a}-{b} in the same direction",
+                faces.0, faces.1
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    // Valid meshes can still have warnings
+    pub fn is_valid(&self) -> bool {
+        !self.issues.iter().any(Issue::is_error)
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Issue> {
+        self.issues.iter().filter(|i| i.is_error())
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Issue> {
+        self.issues.iter().filter(|i| !i.is_error())
+    }
+
+    // At most `limit` warnings, followed by a count of the ones left out
+    pub fn warning_summary(&self, limit: usize) -> Vec<String> {
+        let warnings: Vec<_> = self.warnings().collect();
+        let mut summary: Vec<String> = warnings
+            .iter()
+            .take(limit)
+            .map(|warning| format!("warning: {warning}"))
+            .collect();
+
+        if warnings.len() > limit {
+            summary.push(format!("{} more warnings", warnings.len() - limit));
+        }
+
+        summary
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            let severity = match issue.is_error() {
+                true => "error",
+                false => "warning",
+            };
+
+            writeln!(f, "{severity}: {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn validate(mesh: &Mesh) -> ValidationReport {
+    let mut issues = Vec::new();
+    let count = mesh.vertices.len();
+
+    for (vertex, v) in mesh.vertices.iter().enumerate() {
+        if !(v.x.is_finite() && v.y.is_finite() && v.z.is_finite()) {
+            issues.push(Issue::NonFiniteVertex { vertex });
+        }
+    }
+
+    let attributes = [
+        ("vertex colors", mesh.vertex_colors.as_ref().map(Vec::len)),
+        ("vertex normals", mesh.vertex_normals.as_ref().map(Vec::len)),
+    ];
+
+    for (attribute, found) in attributes {
+        if let Some(found) = found.filter(|&found| found != count) {
+            issues.push(Issue::AttributeCount {
+                attribute,
+                expected: count,
+                found,
+            });
+        }
+    }
+
+    let elements = mesh
+        .faces
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (Element::Face(i), f))
+        .chain(
+            mesh.lines
+                .iter()
+                .enumerate()
+                .map(|(i, l)| (Element::Line(i), l)),
+        );
+
+    for (element, indices) in elements {
+        for &index in indices.iter().filter(|&&index| index >= count) {
+            issues.push(Issue::IndexOutOfRange { element, index });
+        }
+    }
+
+    let mut used = vec![false; count];
+
+    for &index in mesh.faces.iter().chain(&mesh.lines).flatten() {
+        if let Some(used) = used.get_mut(index) {
+            *used = true;
+        }
+    }
+
+    issues.extend(
+        used.iter()
+            .enumerate()
+            .filter(|(_, used)| !**used)
+            .map(|(vertex, _)| Issue::UnusedVertex { vertex }),
+    );
+
+    // The topology checks only make sense for faces that point at real vertices
+    let faces: Vec<(usize, &Vec<usize>)> = mesh
+        .faces
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.iter().all(|&index| index < count))
+        .collect();
+
+    let mut seen: HashMap<Vec<usize>, usize> = HashMap::new();
+
+    for &(face, indices) in &faces {
+        if is_degenerate(mesh, indices) {
+            issues.push(Issue::DegenerateFace { face });
+        }
+
+        let mut key = indices.clone();
+        key.sort_unstable();
+
+        match seen.get(&key) {
+            Some(&original) => issues.push(Issue::DuplicateFace { face, original }),
+            None => {
+                seen.insert(key, face);
+            }
+        }
+    }
+
+    // Each undirected edge with the faces that use it and whether they walk it from the lower index to the higher one
+    let mut edges: HashMap<(usize, usize), Vec<(usize, bool)>> = HashMap::new();
+
+    for &(face, indices) in &faces {
+        for i in 0..indices.len() {
+            let (a, b) = (indices[i], indices[(i + 1) % indices.len()]);
+
+            if a != b {
+                edges
+                    .entry(Mesh::edge_key(a, b))
+                    .or_default()
+                    .push((face, a < b));
+            }
+        }
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|(key, _)| *key);
+
+    for ((a, b), uses) in edges {
+        match uses.as_slice() {
+            [(first, forward), (second, other)] if forward == other => {
+                issues.push(Issue::InconsistentWinding {
+                    a,
+                    b,
+                    faces: (*first, *second),
+                });
+            }
+            [_, _, _, ..] => issues.push(Issue::NonManifoldEdge {
+                a,
+                b,
+                faces: uses.len(),
+            }),
+            _ => {}
+        }
+    }
+
+    ValidationReport { issues }
+}
+
+// Fewer than three distinct corners, or an area that's negligible next to the face's longest edge
+fn is_degenerate(mesh: &Mesh, face: &[usize]) -> bool {
+    let mut distinct = face.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    if distinct.len() < 3 {
+        return true;
+    }
+
+    // Newell's method, the length of the sum is twice the area even for non-planar polygons
+    let mut normal = Point3D::default();
+    let mut longest: f32 = 0.0;
+
+    for i in 0..face.len() {
+        let a = mesh.vertices[face[i]];
+        let b = mesh.vertices[face[(i + 1) % face.len()]];
+
+        normal += a.cross(&b);
+        longest = longest.max(a.distance(&b));
+    }
+
+    normal.length() <= longest * longest * 1e-6
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flo_canvas::Color;
+
+    fn points(count: usize) -> Vec<Point3D> {
+        [
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+            Point3D::new(0.0, 0.0, -1.0),
+        ][..count]
+            .to_vec()
+    }
+
+    fn issues(vertices: Vec<Point3D>, faces: Vec<Vec<usize>>) -> Vec<Issue> {
+        validate(&Mesh::new("test", vertices, faces)).issues
+    }
+
+    #[test]
+    fn closed_meshes_have_no_issues() {
+        let faces = vec![vec![0, 2, 1], vec![0, 1, 3], vec![1, 2, 3], vec![0, 3, 2]];
+
+        assert_eq!(issues(points(4), faces), vec![]);
+    }
+
+    #[test]
+    fn reports_indices_out_of_range() {
+        let mesh = Mesh::new("test", points(3), vec![vec![0, 1, 5]]).with_lines(vec![vec![2, 7]]);
+        let report = validate(&mesh);
+
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.errors().cloned().collect::<Vec<_>>(),
+            vec![
+                Issue::IndexOutOfRange {
+                    element: Element::Face(0),
+                    index: 5
+                },
+                Issue::IndexOutOfRange {
+                    element: Element::Line(0),
+                    index: 7
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_non_finite_vertices() {
+        let mut vertices = points(3);
+        vertices[1].y = f32::NAN;
+
+        assert_eq!(
+            issues(vertices, vec![vec![0, 1, 2]]),
+            vec![Issue::NonFiniteVertex { vertex: 1 }]
+        );
+    }
+
+    #[test]
+    fn reports_attribute_counts() {
+        let mesh = Mesh::new("test", points(3), vec![vec![0, 1, 2]])
+            .with_vertex_colors(vec![Color::Rgba(1.0, 1.0, 1.0, 1.0); 2]);
+
+        assert_eq!(
+            validate(&mesh).issues,
+            vec![Issue::AttributeCount {
+                attribute: "vertex colors",
+                expected: 3,
+                found: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_degenerate_faces() {
+        let vertices = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+        ];
+        let report = validate(&Mesh::new("test", vertices, vec![vec![0, 1, 2]]));
+
+        assert!(report.is_valid());
+        assert_eq!(report.issues, vec![Issue::DegenerateFace { face: 0 }]);
+    }
+
+    #[test]
+    fn reports_duplicate_faces() {
+        let found = issues(points(3), vec![vec![0, 1, 2], vec![1, 2, 0]]);
+
+        assert!(found.contains(&Issue::DuplicateFace {
+            face: 1,
+            original: 0
+        }));
+    }
+
+    #[test]
+    fn reports_unused_vertices() {
+        assert_eq!(
+            issues(points(4), vec![vec![0, 1, 2]]),
+            vec![Issue::UnusedVertex { vertex: 3 }]
+        );
+    }
+
+    #[test]
+    fn reports_non_manifold_edges() {
+        let faces = vec![vec![0, 1, 2], vec![1, 0, 3], vec![1, 0, 4]];
+
+        assert_eq!(
+            issues(points(5), faces),
+            vec![Issue::NonManifoldEdge {
+                a: 0,
+                b: 1,
+                faces: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_inconsistent_winding() {
+        assert_eq!(
+            issues(points(4), vec![vec![0, 1, 2], vec![0, 1, 3]]),
+            vec![Issue::InconsistentWinding {
+                a: 0,
+                b: 1,
+                faces: (0, 1)
+            }]
+        );
+    }
+
+    #[test]
+    fn summaries_stop_at_the_limit() {
+        let mut vertices = points(3);
+        vertices.resize(15, Point3D::default());
+        let report = validate(&Mesh::new("test", vertices, vec![vec![0, 1, 2]]));
+
+        let summary = report.warning_summary(10);
+
+        assert_eq!(summary.len(), 11);
+        assert_eq!(
+            summary[0],
+            "warning: vertex 3 isn't used by any face or line"
+        );
+        assert_eq!(summary[10], "2 more warnings");
+        assert_eq!(report.warning_summary(12).len(), 12);
+    }
+}
@@ -1,9 +1,10 @@
 use flo_canvas::{DrawingTarget, GraphicsContext as _, GraphicsPrimitives as _};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 use crate::color;
-use crate::constants::{CANVAS_HEIGHT, CANVAS_WIDTH};
+use crate::viewport::Viewport;
 
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Point2D {
     pub x: f32,
     pub y: f32,
@@ -14,13 +15,61 @@ impl Point2D {
         Self { x, y }
     }
 
-    pub fn screen(&self) -> Point2D {
-        Point2D {
-            x: self.x * (CANVAS_WIDTH / 2.0),
-            y: self.y * (CANVAS_HEIGHT / 2.0),
+    pub fn dot(&self, other: &Point2D) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    // z component of the 3D cross product, positive when `other` is counter-clockwise from `self`
+    pub fn cross(&self, other: &Point2D) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    // Zero length vectors stay zero instead of turning into NaN
+    pub fn normalize(&self) -> Self {
+        match self.length() {
+            0.0 => *self,
+            length => *self / length,
+        }
+    }
+
+    pub fn distance(&self, other: &Point2D) -> f32 {
+        (*other - *self).length()
+    }
+
+    pub fn lerp(&self, other: &Point2D, t: f32) -> Self {
+        *self + (*other - *self) * t
+    }
+
+    pub fn min(&self, other: &Point2D) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
         }
     }
 
+    pub fn max(&self, other: &Point2D) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    pub fn approx_eq(&self, other: &Point2D, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    pub fn screen(&self, viewport: &Viewport) -> Point2D {
+        viewport.to_screen(self)
+    }
+
     pub fn point(&self, canvas: &DrawingTarget) {
         let radius = 4.0;
 
@@ -32,3 +81,63 @@ impl Point2D {
         });
     }
 }
+
+impl Add for Point2D {
+    type Output = Point2D;
+
+    fn add(self, rhs: Point2D) -> Point2D {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, rhs: Point2D) -> Point2D {
+        Point2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f32> for Point2D {
+    type Output = Point2D;
+
+    fn mul(self, rhs: f32) -> Point2D {
+        Point2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Mul<Point2D> for f32 {
+    type Output = Point2D;
+
+    fn mul(self, rhs: Point2D) -> Point2D {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Point2D {
+    type Output = Point2D;
+
+    fn div(self, rhs: f32) -> Point2D {
+        Point2D::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Neg for Point2D {
+    type Output = Point2D;
+
+    fn neg(self) -> Point2D {
+        Point2D::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point2D {
+    fn add_assign(&mut self, rhs: Point2D) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point2D {
+    fn sub_assign(&mut self, rhs: Point2D) {
+        *self = *self - rhs;
+    }
+}
@@ -1,9 +1,14 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::mat4::Mat4;
 use crate::point2d::Point2D;
+use crate::point4d::Point4D;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Point3D {
-    x: f32,
-    y: f32,
-    z: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
 }
 
 impl Point3D {
@@ -11,63 +16,86 @@ impl Point3D {
         Self { x, y, z }
     }
 
-    pub fn translate_x(&self, delta: f32) -> Self {
-        Self {
-            x: self.x + delta,
-            y: self.y,
-            z: self.z,
-        }
+    pub fn dot(&self, other: &Point3D) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
     }
 
-    pub fn translate_y(&self, delta: f32) -> Self {
+    pub fn cross(&self, other: &Point3D) -> Self {
         Self {
-            x: self.x,
-            y: self.y + delta,
-            z: self.z,
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
         }
     }
 
-    pub fn translate_z(&self, delta: f32) -> Self {
-        Self {
-            x: self.x,
-            y: self.y,
-            z: self.z + delta,
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    // Zero length vectors stay zero instead of turning into NaN
+    pub fn normalize(&self) -> Self {
+        match self.length() {
+            0.0 => *self,
+            length => *self / length,
         }
     }
 
-    pub fn rotate_x(&self, angle: f32) -> Self {
-        let c = f32::cos(angle.to_radians());
-        let s = f32::sin(angle.to_radians());
+    pub fn distance(&self, other: &Point3D) -> f32 {
+        (*other - *self).length()
+    }
+
+    pub fn lerp(&self, other: &Point3D, t: f32) -> Self {
+        *self + (*other - *self) * t
+    }
 
+    pub fn min(&self, other: &Point3D) -> Self {
         Self {
-            x: self.x,
-            y: self.y * c - self.z * s,
-            z: self.y * s + self.z * c,
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
         }
     }
 
-    pub fn rotate_y(&self, angle: f32) -> Self {
-        let c = f32::cos(angle.to_radians());
-        let s = f32::sin(angle.to_radians());
-
+    pub fn max(&self, other: &Point3D) -> Self {
         Self {
-            x: self.x * c - self.z * s,
-            y: self.y,
-            z: self.x * s + self.z * c,
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
         }
     }
 
-    pub fn rotate_z(&self, angle: f32) -> Self {
-        let c = f32::cos(angle.to_radians());
-        let s = f32::sin(angle.to_radians());
+    pub fn approx_eq(&self, other: &Point3D, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
 
-        Self {
-            x: self.x * c - self.y * s,
-            y: self.x * s + self.y * c,
-            z: self.z,
+    // Treats the point as (x, y, z, 1) and divides by w in case the matrix isn't affine
+    pub fn transform(&self, m: &Mat4) -> Self {
+        let p = self.to_clip(m);
+
+        match p.w {
+            1.0 | 0.0 => Self::new(p.x, p.y, p.z),
+            _ => p.divide(),
         }
     }
 
+    // Applies the matrix without the divide by w, keeping enough information to clip against the view frustum
+    pub fn to_clip(&self, m: &Mat4) -> Point4D {
+        let r = &m.rows;
+
+        Point4D::new(
+            r[0][0] * self.x + r[0][1] * self.y + r[0][2] * self.z + r[0][3],
+            r[1][0] * self.x + r[1][1] * self.y + r[1][2] * self.z + r[1][3],
+            r[2][0] * self.x + r[2][1] * self.y + r[2][2] * self.z + r[2][3],
+            r[3][0] * self.x + r[3][1] * self.y + r[3][2] * self.z + r[3][3],
+        )
+    }
+
     pub fn project(&self) -> Point2D {
         match self.z {
             0.0 => Point2D::new(0.0, 0.0),
@@ -79,3 +107,63 @@ impl Point3D {
         Point2D::new(self.x, self.y)
     }
 }
+
+impl Add for Point3D {
+    type Output = Point3D;
+
+    fn add(self, rhs: Point3D) -> Point3D {
+        Point3D::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point3D {
+    type Output = Point3D;
+
+    fn sub(self, rhs: Point3D) -> Point3D {
+        Point3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<f32> for Point3D {
+    type Output = Point3D;
+
+    fn mul(self, rhs: f32) -> Point3D {
+        Point3D::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Point3D> for f32 {
+    type Output = Point3D;
+
+    fn mul(self, rhs: Point3D) -> Point3D {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Point3D {
+    type Output = Point3D;
+
+    fn div(self, rhs: f32) -> Point3D {
+        Point3D::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl Neg for Point3D {
+    type Output = Point3D;
+
+    fn neg(self) -> Point3D {
+        Point3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl AddAssign for Point3D {
+    fn add_assign(&mut self, rhs: Point3D) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point3D {
+    fn sub_assign(&mut self, rhs: Point3D) {
+        *self = *self - rhs;
+    }
+}
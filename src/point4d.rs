@@ -0,0 +1,30 @@
+use crate::point3d::Point3D;
+
+// Homogeneous point in clip space, produced by Point3D::to_clip before the perspective divide
+#[derive(Clone, Copy)]
+pub struct Point4D {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Point4D {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn lerp(&self, other: &Point4D, t: f32) -> Self {
+        Self {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+            z: self.z + (other.z - self.z) * t,
+            w: self.w + (other.w - self.w) * t,
+        }
+    }
+
+    // Perspective divide into normalized device coordinates, only meaningful once the point has been clipped
+    pub fn divide(&self) -> Point3D {
+        Point3D::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::Mul;
+
+use crate::mat4::Mat4;
+use crate::point3d::Point3D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quaternion {
+    pub fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    // Angle is in degrees to match Mat4::rotation, the axis does not need to be normalized
+    pub fn from_axis_angle(axis: &Point3D, angle: f32) -> Self {
+        if axis.length() == 0.0 {
+            return Self::identity();
+        }
+
+        let half = angle.to_radians() / 2.0;
+        let Point3D { x, y, z } = axis.normalize() * f32::sin(half);
+
+        Self::new(f32::cos(half), x, y, z)
+    }
+
+    // Rotates about x first, then y, then z (same as Mat4::rotation_z(z) * Mat4::rotation_y(y) * Mat4::rotation_x(x))
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Self {
+        let qx = Self::from_axis_angle(&Point3D::new(1.0, 0.0, 0.0), x);
+        // rotation_y turns the other way from the right-hand rule, see Mat4::rotation_y
+        let qy = Self::from_axis_angle(&Point3D::new(0.0, -1.0, 0.0), y);
+        let qz = Self::from_axis_angle(&Point3D::new(0.0, 0.0, 1.0), z);
+
+        qz * qy * qx
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+
+        match length {
+            0.0 => Self::identity(),
+            _ => Self::new(
+                self.w / length,
+                self.x / length,
+                self.y / length,
+                self.z / length,
+            ),
+        }
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    // Spherical linear interpolation along the shortest arc, falls back to a normalized lerp when nearly parallel
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let mut other = *other;
+        let mut cos_theta = self.dot(&other);
+
+        if cos_theta < 0.0 {
+            other = Self::new(-other.w, -other.x, -other.y, -other.z);
+            cos_theta = -cos_theta;
+        }
+
+        let (a, b) = if cos_theta > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+
+            (
+                f32::sin((1.0 - t) * theta) / sin_theta,
+                f32::sin(t * theta) / sin_theta,
+            )
+        };
+
+        Self::new(
+            a * self.w + b * other.w,
+            a * self.x + b * other.x,
+            a * self.y + b * other.y,
+            a * self.z + b * other.z,
+        )
+        .normalize()
+    }
+
+    pub fn to_mat4(&self) -> Mat4 {
+        let Self { w, x, y, z } = self.normalize();
+
+        Mat4::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+                0.0,
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+                0.0,
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+// Hamilton product, `a * b` rotates by `b` first and then by `a`
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl From<Quaternion> for Mat4 {
+    fn from(q: Quaternion) -> Mat4 {
+        q.to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euler_angles_match_the_matrix_rotations() {
+        let q = Mat4::from(Quaternion::from_euler(30.0, -45.0, 60.0));
+        let m = Mat4::rotation_z(60.0) * Mat4::rotation_y(-45.0) * Mat4::rotation_x(30.0);
+
+        for (a, b) in q.rows.iter().flatten().zip(m.rows.iter().flatten()) {
+            assert!((a - b).abs() < 1e-5, "{q:?} != {m:?}");
+        }
+    }
+}
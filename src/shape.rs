@@ -1,14 +1,30 @@
+use crate::mesh::Mesh;
 use crate::point3d::Point3D;
 
+pub mod archimedean;
+pub mod cone;
 pub mod cube;
-pub mod penger;
+pub mod cylinder;
+pub mod grid;
+pub mod icosphere;
+pub mod isosurface;
+pub mod parametric;
+pub mod platonic;
+pub mod polyhedron;
+pub mod torus;
+pub mod uv_sphere;
+
+// Built-in shapes generated from the OBJ files in content/ by build.rs, e.g. `penger::Penger` with its data in `Penger::VERTICES`
+include!(concat!(env!("OUT_DIR"), "/shapes.rs"));
 
 pub trait Shape {
-    fn vertices() -> Option<Vec<Point3D>> {
-        None
-    }
+    fn name() -> &'static str;
+
+    fn vertices() -> Vec<Point3D>;
+
+    fn indices() -> Vec<Vec<usize>>;
 
-    fn indices() -> Option<Vec<Vec<usize>>> {
-        None
+    fn mesh() -> Mesh {
+        Mesh::new(Self::name(), Self::vertices(), Self::indices())
     }
 }
@@ -0,0 +1,174 @@
+use std::f32::consts::SQRT_2;
+
+use super::Shape;
+use super::polyhedron::{self, PHI};
+use crate::point3d::Point3D;
+
+// The snub cube and snub dodecahedron are left out, they're chiral and have no neat closed form coordinates
+
+pub struct TruncatedTetrahedron;
+pub struct Cuboctahedron;
+pub struct TruncatedCube;
+pub struct TruncatedOctahedron;
+pub struct Rhombicuboctahedron;
+pub struct TruncatedCuboctahedron;
+pub struct Icosidodecahedron;
+pub struct TruncatedDodecahedron;
+pub struct TruncatedIcosahedron;
+pub struct Rhombicosidodecahedron;
+pub struct TruncatedIcosidodecahedron;
+
+impl Shape for TruncatedTetrahedron {
+    fn name() -> &'static str {
+        "truncated_tetrahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        // Only the permutations with an even number of negative coordinates
+        polyhedron::permutations(&[[3.0, 1.0, 1.0]])
+            .into_iter()
+            .filter(|v| v.x * v.y * v.z > 0.0)
+            .collect()
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Cuboctahedron {
+    fn name() -> &'static str {
+        "cuboctahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 1.0, 0.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for TruncatedCube {
+    fn name() -> &'static str {
+        "truncated_cube"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[SQRT_2 - 1.0, 1.0, 1.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for TruncatedOctahedron {
+    fn name() -> &'static str {
+        "truncated_octahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[0.0, 1.0, 2.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Rhombicuboctahedron {
+    fn name() -> &'static str {
+        "rhombicuboctahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 1.0, 1.0 + SQRT_2]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for TruncatedCuboctahedron {
+    fn name() -> &'static str {
+        "truncated_cuboctahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 1.0 + SQRT_2, 1.0 + 2.0 * SQRT_2]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Icosidodecahedron {
+    fn name() -> &'static str {
+        "icosidodecahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[[0.0, 0.0, PHI], [0.5, PHI / 2.0, PHI * PHI / 2.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for TruncatedDodecahedron {
+    fn name() -> &'static str {
+        "truncated_dodecahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[
+            [0.0, 1.0 / PHI, 2.0 + PHI],
+            [1.0 / PHI, PHI, 2.0 * PHI],
+            [PHI, 2.0, PHI + 1.0],
+        ])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+// Football (soccer ball)
+impl Shape for TruncatedIcosahedron {
+    fn name() -> &'static str {
+        "truncated_icosahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[
+            [0.0, 1.0, 3.0 * PHI],
+            [1.0, 2.0 + PHI, 2.0 * PHI],
+            [PHI, 2.0, PHI * PHI * PHI],
+        ])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Rhombicosidodecahedron {
+    fn name() -> &'static str {
+        "rhombicosidodecahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[
+            [1.0, 1.0, PHI * PHI * PHI],
+            [PHI * PHI, PHI, 2.0 * PHI],
+            [2.0 + PHI, 0.0, PHI * PHI],
+        ])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for TruncatedIcosidodecahedron {
+    fn name() -> &'static str {
+        "truncated_icosidodecahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[
+            [1.0 / PHI, 1.0 / PHI, 3.0 + PHI],
+            [2.0 / PHI, PHI, 1.0 + 2.0 * PHI],
+            [1.0 / PHI, PHI * PHI, 3.0 * PHI - 1.0],
+            [2.0 * PHI - 1.0, 2.0, 2.0 + PHI],
+            [PHI, 3.0, 2.0 * PHI],
+        ])
+    }
+
+    polyhedron::hull_indices!();
+}
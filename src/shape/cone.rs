@@ -0,0 +1,56 @@
+use std::f32::consts::PI;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Upright along the y axis and centered on the origin, the apex is a single vertex and the base is one polygon
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cone {
+    pub radius: f32,
+    pub height: f32,
+    pub segments: usize,
+    pub cap: bool,
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            height: 1.0,
+            segments: 24,
+            cap: true,
+        }
+    }
+}
+
+impl Cone {
+    pub fn build(&self) -> Mesh {
+        let segments = self.segments.max(3);
+
+        let mut vertices: Vec<Point3D> = (0..segments)
+            .map(|segment| {
+                let (sin_theta, cos_theta) =
+                    (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+
+                Point3D::new(
+                    self.radius * cos_theta,
+                    -self.height / 2.0,
+                    self.radius * sin_theta,
+                )
+            })
+            .collect();
+
+        let apex = vertices.len();
+        vertices.push(Point3D::new(0.0, self.height / 2.0, 0.0));
+
+        let mut faces: Vec<Vec<usize>> = (0..segments)
+            .map(|segment| vec![segment, apex, (segment + 1) % segments])
+            .collect();
+
+        if self.cap {
+            faces.push((0..segments).collect());
+        }
+
+        Mesh::new("cone", vertices, faces)
+    }
+}
@@ -3,8 +3,12 @@ use crate::point3d::Point3D;
 pub struct Cube;
 
 impl super::Shape for Cube {
-    fn vertices() -> Option<Vec<crate::point3d::Point3D>> {
-        Some(vec![
+    fn name() -> &'static str {
+        "cube"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        vec![
             // Back Face
             Point3D::new(0.25, 0.25, 0.25),
             Point3D::new(-0.25, 0.25, 0.25),
@@ -15,17 +19,17 @@ impl super::Shape for Cube {
             Point3D::new(-0.25, 0.25, -0.25),
             Point3D::new(-0.25, -0.25, -0.25),
             Point3D::new(0.25, -0.25, -0.25),
-        ])
+        ]
     }
 
-    fn indices() -> Option<Vec<Vec<usize>>> {
-        Some(vec![
+    fn indices() -> Vec<Vec<usize>> {
+        vec![
             vec![0, 1, 2, 3], // Back
-            vec![4, 5, 6, 7], // Front
-            vec![0, 4],       // Top Right
-            vec![1, 5],       // Top Left
-            vec![2, 6],       // Bottom Left
-            vec![3, 7],       // Bottom Right
-        ])
+            vec![7, 6, 5, 4], // Front
+            vec![0, 4, 5, 1], // Top
+            vec![3, 2, 6, 7], // Bottom
+            vec![1, 5, 6, 2], // Left
+            vec![0, 3, 7, 4], // Right
+        ]
     }
 }
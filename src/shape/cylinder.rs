@@ -0,0 +1,77 @@
+use std::f32::consts::PI;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Upright along the y axis and centered on the origin, `stacks` splits the side into bands and each cap is one polygon
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cylinder {
+    pub radius: f32,
+    pub height: f32,
+    pub segments: usize,
+    pub stacks: usize,
+    pub caps: bool,
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            height: 1.0,
+            segments: 24,
+            stacks: 1,
+            caps: true,
+        }
+    }
+}
+
+impl Cylinder {
+    pub fn build(&self) -> Mesh {
+        let segments = self.segments.max(3);
+        let stacks = self.stacks.max(1);
+
+        let mut vertices = Vec::with_capacity((stacks + 1) * segments);
+
+        for stack in 0..=stacks {
+            let y = self.height * (stack as f32 / stacks as f32 - 0.5);
+
+            for segment in 0..segments {
+                let (sin_theta, cos_theta) =
+                    (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+
+                vertices.push(Point3D::new(
+                    self.radius * cos_theta,
+                    y,
+                    self.radius * sin_theta,
+                ));
+            }
+        }
+
+        let at = |stack: usize, segment: usize| stack * segments + segment % segments;
+
+        let mut faces = Vec::new();
+
+        for stack in 0..stacks {
+            for segment in 0..segments {
+                faces.push(vec![
+                    at(stack, segment),
+                    at(stack + 1, segment),
+                    at(stack + 1, segment + 1),
+                    at(stack, segment + 1),
+                ]);
+            }
+        }
+
+        if self.caps {
+            faces.push((0..segments).map(|segment| at(0, segment)).collect());
+            faces.push(
+                (0..segments)
+                    .rev()
+                    .map(|segment| at(stacks, segment))
+                    .collect(),
+            );
+        }
+
+        Mesh::new("cylinder", vertices, faces)
+    }
+}
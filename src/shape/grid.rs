@@ -0,0 +1,58 @@
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Flat grid of quads on the xz plane centered on the origin
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grid {
+    pub width: f32,
+    pub depth: f32,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            depth: 1.0,
+            columns: 10,
+            rows: 10,
+        }
+    }
+}
+
+impl Grid {
+    pub fn build(&self) -> Mesh {
+        let columns = self.columns.max(1);
+        let rows = self.rows.max(1);
+
+        let mut vertices = Vec::with_capacity((rows + 1) * (columns + 1));
+
+        for row in 0..=rows {
+            let z = self.depth * (row as f32 / rows as f32 - 0.5);
+
+            for column in 0..=columns {
+                let x = self.width * (column as f32 / columns as f32 - 0.5);
+
+                vertices.push(Point3D::new(x, 0.0, z));
+            }
+        }
+
+        let at = |row: usize, column: usize| row * (columns + 1) + column;
+
+        let mut faces = Vec::with_capacity(rows * columns);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                faces.push(vec![
+                    at(row, column),
+                    at(row + 1, column),
+                    at(row + 1, column + 1),
+                    at(row, column + 1),
+                ]);
+            }
+        }
+
+        Mesh::new("grid", vertices, faces)
+    }
+}
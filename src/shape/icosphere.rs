@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Icosahedron with every triangle split into four `subdivisions` times, pushed out onto the sphere
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Icosphere {
+    pub radius: f32,
+    pub subdivisions: usize,
+}
+
+impl Default for Icosphere {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            subdivisions: 2,
+        }
+    }
+}
+
+impl Icosphere {
+    pub fn build(&self) -> Mesh {
+        let t = (1.0 + f32::sqrt(5.0)) / 2.0;
+
+        let mut vertices: Vec<Point3D> = [
+            (-1.0, t, 0.0),
+            (1.0, t, 0.0),
+            (-1.0, -t, 0.0),
+            (1.0, -t, 0.0),
+            (0.0, -1.0, t),
+            (0.0, 1.0, t),
+            (0.0, -1.0, -t),
+            (0.0, 1.0, -t),
+            (t, 0.0, -1.0),
+            (t, 0.0, 1.0),
+            (-t, 0.0, -1.0),
+            (-t, 0.0, 1.0),
+        ]
+        .into_iter()
+        .map(|(x, y, z)| Point3D::new(x, y, z).normalize())
+        .collect();
+
+        let mut faces: Vec<[usize; 3]> = vec![
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ];
+
+        for _ in 0..self.subdivisions {
+            // Neighbouring triangles share the midpoint of their common edge instead of each adding their own
+            let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+            let mut midpoint = |a: usize, b: usize| {
+                *midpoints.entry(Mesh::edge_key(a, b)).or_insert_with(|| {
+                    vertices.push(vertices[a].lerp(&vertices[b], 0.5).normalize());
+                    vertices.len() - 1
+                })
+            };
+
+            faces = faces
+                .iter()
+                .flat_map(|&[a, b, c]| {
+                    let ab = midpoint(a, b);
+                    let bc = midpoint(b, c);
+                    let ca = midpoint(c, a);
+
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+
+        let vertices = vertices.into_iter().map(|v| v * self.radius).collect();
+        let faces = faces.into_iter().map(Vec::from).collect();
+
+        Mesh::new("icosphere", vertices, faces)
+    }
+}
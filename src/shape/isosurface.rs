@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Corners of a cell are numbered x + 2y + 4z, the twelve edges join corners that differ along one axis
+const EDGES: [(usize, usize); 12] = [
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+// Crossings this close to either end of a cell edge (as a fraction of it) are moved onto the node, a vertex
+// just off a node would only make slivers with no area, once snapped they share the node and collapse instead
+const SNAP: f32 = 1e-2;
+
+// Triangulated surface where the field crosses `level`, found with marching cubes
+// The field is negative inside like a signed distance field, so the triangles face towards increasing values
+pub struct Isosurface<F: Fn(Point3D) -> f32> {
+    name: String,
+    f: F,
+    min: Point3D,
+    max: Point3D,
+    resolution: usize,
+    level: f32,
+}
+
+impl<F: Fn(Point3D) -> f32> Isosurface<F> {
+    pub fn new(name: &str, f: F) -> Self {
+        Self {
+            name: name.to_owned(),
+            f,
+            min: Point3D::new(-1.0, -1.0, -1.0),
+            max: Point3D::new(1.0, 1.0, 1.0),
+            resolution: 32,
+            level: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, min: Point3D, max: Point3D) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    // Number of cells along each axis
+    pub fn with_resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn with_level(mut self, level: f32) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        let n = self.resolution.max(1);
+        let nodes = n + 1;
+        let size = self.max - self.min;
+
+        let node = |i: usize, j: usize, k: usize| i + nodes * (j + nodes * k);
+        let position = |index: usize| {
+            let (i, j, k) = (
+                index % nodes,
+                index / nodes % nodes,
+                index / (nodes * nodes),
+            );
+
+            self.min
+                + Point3D::new(
+                    size.x * i as f32 / n as f32,
+                    size.y * j as f32 / n as f32,
+                    size.z * k as f32 / n as f32,
+                )
+        };
+
+        let values: Vec<f32> = (0..nodes * nodes * nodes)
+            .map(|index| (self.f)(position(index)))
+            .collect();
+
+        // Cells that share an edge share the vertex on it, crossings that land on (or next to) a node are keyed by the node instead
+        let mut welded: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+
+        for k in 0..n {
+            for j in 0..n {
+                for i in 0..n {
+                    let corners: [usize; 8] = std::array::from_fn(|c| {
+                        node(i + (c & 1), j + (c >> 1 & 1), k + (c >> 2 & 1))
+                    });
+                    let case = (0..8)
+                        .filter(|&c| values[corners[c]] < self.level)
+                        .fold(0, |case, c| case | 1 << c);
+
+                    for triangle in &table()[case] {
+                        let face = triangle
+                            .iter()
+                            .map(|&edge| {
+                                let (a, b) = (corners[EDGES[edge].0], corners[EDGES[edge].1]);
+                                let t = (self.level - values[a]) / (values[b] - values[a]);
+
+                                let (key, t) = match t {
+                                    t if t <= SNAP => ((a, a), 0.0),
+                                    t if t >= 1.0 - SNAP => ((b, b), 1.0),
+                                    _ => ((a, b), t),
+                                };
+
+                                *welded.entry(key).or_insert_with(|| {
+                                    vertices.push(position(a).lerp(&position(b), t));
+                                    vertices.len() - 1
+                                })
+                            })
+                            .collect::<Vec<usize>>();
+
+                        // Crossings snapped onto the same node collapse the triangle
+                        if face[0] != face[1] && face[1] != face[2] && face[2] != face[0] {
+                            faces.push(face);
+                        }
+                    }
+                }
+            }
+        }
+
+        // A vertex whose triangles all collapsed is left behind, drop those and renumber the rest
+        let mut remap: Vec<Option<usize>> = vec![None; vertices.len()];
+        let mut kept = Vec::new();
+
+        for &v in faces.iter().flatten() {
+            if remap[v].is_none() {
+                remap[v] = Some(kept.len());
+                kept.push(vertices[v]);
+            }
+        }
+
+        let faces = faces
+            .iter()
+            .map(|face| face.iter().map(|&v| remap[v].unwrap_or_default()).collect())
+            .collect();
+
+        Mesh::new(&self.name, kept, faces)
+    }
+}
+
+// Triangles for each of the 256 inside/outside corner combinations, as triples of edge indices
+// Built by walking the crossings around each cell face instead of copying the usual hand-made table
+fn table() -> &'static Vec<Vec<[usize; 3]>> {
+    static TABLE: OnceLock<Vec<Vec<[usize; 3]>>> = OnceLock::new();
+
+    TABLE.get_or_init(|| (0..256).map(triangulate).collect())
+}
+
+fn triangulate(case: usize) -> Vec<[usize; 3]> {
+    let inside = |corner: usize| case & (1 << corner) != 0;
+    let edge = |a: usize, b: usize| {
+        EDGES
+            .iter()
+            .position(|&e| e == (a.min(b), a.max(b)))
+            .unwrap_or_default()
+    };
+
+    // Segments run from where the walk around a face enters the inside to where it next crosses back out
+    // Each one goes to the next crossing, so on ambiguous faces the inside corners are cut off separately
+    // Both cells sharing a face see the same corners, so they always agree and the surface has no cracks
+    let mut next: HashMap<usize, usize> = HashMap::new();
+
+    for axis in 0..3 {
+        for side in 0..2 {
+            let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+            let corner = |du: usize, dv: usize| side << axis | du << u | dv << v;
+
+            // Counter-clockwise seen from outside the cell
+            let mut ring = [corner(0, 0), corner(1, 0), corner(1, 1), corner(0, 1)];
+
+            if side == 0 {
+                ring.reverse();
+            }
+
+            let crossings: Vec<(usize, bool)> = (0..4)
+                .filter(|&c| inside(ring[c]) != inside(ring[(c + 1) % 4]))
+                .map(|c| (edge(ring[c], ring[(c + 1) % 4]), inside(ring[c])))
+                .collect();
+
+            for (c, &(from, exits)) in crossings.iter().enumerate() {
+                if !exits {
+                    next.insert(from, crossings[(c + 1) % crossings.len()].0);
+                }
+            }
+        }
+    }
+
+    // Follow the segments into closed loops and fan each one into triangles
+    let mut triangles = Vec::new();
+
+    while let Some(&start) = next.keys().min() {
+        let mut ring = vec![start];
+        let mut current = next.remove(&start).unwrap_or(start);
+
+        while current != start {
+            ring.push(current);
+            current = next.remove(&current).unwrap_or(start);
+        }
+
+        // A loop can pass all four edges of an ambiguous face, a fan triangle lying flat in that face would
+        // overlap the neighbouring cell's surface, so fan out from a crossing where that can't happen
+        let on_face = |edge: usize, axis: usize, side: usize| {
+            let (a, b) = EDGES[edge];
+
+            a >> axis & 1 == side && b >> axis & 1 == side
+        };
+        let flat = |triangle: &[usize; 3]| {
+            (0..3).any(|axis| {
+                (0..2).any(|side| triangle.iter().all(|&edge| on_face(edge, axis, side)))
+            })
+        };
+        let ring = &ring;
+        let fan = |start: usize| {
+            (1..ring.len().saturating_sub(1)).map(move |i| {
+                let at = |i: usize| ring[(start + i) % ring.len()];
+
+                [at(0), at(i), at(i + 1)]
+            })
+        };
+        let start = (0..ring.len())
+            .find(|&start| !fan(start).any(|triangle| flat(&triangle)))
+            .unwrap_or(0);
+
+        triangles.extend(fan(start));
+    }
+
+    triangles
+}
+
+pub fn gyroid() -> Mesh {
+    let scale = std::f32::consts::PI;
+
+    Isosurface::new("gyroid", |p| {
+        let p = p * scale;
+
+        p.x.sin() * p.y.cos() + p.y.sin() * p.z.cos() + p.z.sin() * p.x.cos()
+    })
+    .with_resolution(40)
+    .build()
+}
+
+// Each ball contributes radius² / distance², the surface is where the total reaches 1
+pub fn metaballs(balls: &[(Point3D, f32)]) -> Mesh {
+    Isosurface::new("metaballs", |p| {
+        let total: f32 = balls
+            .iter()
+            .map(|(center, radius)| radius * radius / center.distance(&p).powi(2).max(f32::EPSILON))
+            .sum();
+
+        1.0 - total
+    })
+    .with_resolution(40)
+    .build()
+}
+
+// Signed distance to a torus around the y axis
+pub fn torus_sdf(major_radius: f32, minor_radius: f32) -> Mesh {
+    let extent = major_radius + minor_radius * 1.5;
+
+    Isosurface::new("torus_sdf", |p| {
+        let ring = Point3D::new(p.x, 0.0, p.z).length() - major_radius;
+
+        f32::sqrt(ring * ring + p.y * p.y) - minor_radius
+    })
+    .with_bounds(
+        Point3D::new(-extent, -extent, -extent),
+        Point3D::new(extent, extent, extent),
+    )
+    .build()
+}
@@ -0,0 +1,195 @@
+use std::f32::consts::TAU;
+use std::ops::Range;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// How the end of a parameter's range joins back onto its start
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Wrap {
+    // The ends stay apart, both are sampled
+    Open,
+    // f(end) == f(start), like the angle around a torus
+    Closed,
+    // f(end, v) == f(start, start_v + end_v - v), the other parameter is mirrored across the seam (Möbius strip, Klein bottle)
+    Flipped,
+}
+
+impl Wrap {
+    // Closed domains skip the end since it's the same point as the start
+    fn samples(&self, range: &Range<f32>, steps: usize) -> Vec<f32> {
+        let steps = steps.max(1);
+        let count = match self {
+            Wrap::Open => steps + 1,
+            _ => steps,
+        };
+
+        (0..count)
+            .map(|i| range.start + (range.end - range.start) * i as f32 / steps as f32)
+            .collect()
+    }
+
+    // Index of the sample at `start + end - value`
+    fn mirror(&self, index: usize, count: usize) -> usize {
+        match self {
+            Wrap::Open => count - 1 - index,
+            _ => (count - index) % count,
+        }
+    }
+}
+
+// Surface f(u, v) drawn as its u and v isolines
+pub struct Surface<F: Fn(f32, f32) -> Point3D> {
+    name: String,
+    f: F,
+    u: (Range<f32>, usize, Wrap),
+    v: (Range<f32>, usize, Wrap),
+}
+
+impl<F: Fn(f32, f32) -> Point3D> Surface<F> {
+    pub fn new(name: &str, f: F) -> Self {
+        Self {
+            name: name.to_owned(),
+            f,
+            u: (0.0..1.0, 32, Wrap::Open),
+            v: (0.0..1.0, 32, Wrap::Open),
+        }
+    }
+
+    pub fn with_u(mut self, range: Range<f32>, steps: usize, wrap: Wrap) -> Self {
+        self.u = (range, steps, wrap);
+        self
+    }
+
+    pub fn with_v(mut self, range: Range<f32>, steps: usize, wrap: Wrap) -> Self {
+        self.v = (range, steps, wrap);
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        let (u_range, u_steps, u_wrap) = &self.u;
+        let (v_range, v_steps, v_wrap) = &self.v;
+
+        let us = u_wrap.samples(u_range, *u_steps);
+        let vs = v_wrap.samples(v_range, *v_steps);
+        let (nu, nv) = (us.len(), vs.len());
+
+        let vertices = us
+            .iter()
+            .flat_map(|&u| vs.iter().map(move |&v| (u, v)))
+            .map(|(u, v)| (self.f)(u, v))
+            .collect();
+
+        let at = |i: usize, j: usize| i * nv + j;
+        let mut lines = Vec::with_capacity(nu + nv);
+
+        // Lines of constant u, running along v and across the v seam
+        for i in 0..nu {
+            let mut line: Vec<usize> = (0..nv).map(|j| at(i, j)).collect();
+
+            match v_wrap {
+                Wrap::Open => {}
+                Wrap::Closed => line.push(at(i, 0)),
+                Wrap::Flipped => line.push(at(u_wrap.mirror(i, nu), 0)),
+            }
+
+            lines.push(line);
+        }
+
+        // Lines of constant v, running along u and across the u seam
+        for j in 0..nv {
+            let mut line: Vec<usize> = (0..nu).map(|i| at(i, j)).collect();
+
+            match u_wrap {
+                Wrap::Open => {}
+                Wrap::Closed => line.push(at(0, j)),
+                Wrap::Flipped => line.push(at(0, v_wrap.mirror(j, nv))),
+            }
+
+            lines.push(line);
+        }
+
+        Mesh::new(&self.name, vertices, Vec::new()).with_lines(lines)
+    }
+}
+
+// Curve f(t) drawn as a single polyline, a flipped curve has nothing to mirror so it's the same as a closed one
+pub struct Curve<F: Fn(f32) -> Point3D> {
+    name: String,
+    f: F,
+    t: (Range<f32>, usize, Wrap),
+}
+
+impl<F: Fn(f32) -> Point3D> Curve<F> {
+    pub fn new(name: &str, f: F) -> Self {
+        Self {
+            name: name.to_owned(),
+            f,
+            t: (0.0..1.0, 64, Wrap::Open),
+        }
+    }
+
+    pub fn with_t(mut self, range: Range<f32>, steps: usize, wrap: Wrap) -> Self {
+        self.t = (range, steps, wrap);
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        let (range, steps, wrap) = &self.t;
+
+        let vertices: Vec<Point3D> = wrap
+            .samples(range, *steps)
+            .into_iter()
+            .map(&self.f)
+            .collect();
+
+        let mut line: Vec<usize> = (0..vertices.len()).collect();
+
+        if *wrap != Wrap::Open {
+            line.push(0);
+        }
+
+        Mesh::new(&self.name, vertices, Vec::new()).with_lines(vec![line])
+    }
+}
+
+pub fn mobius_strip() -> Mesh {
+    Surface::new("mobius_strip", |u, v| {
+        let r = 1.0 + v / 2.0 * f32::cos(u / 2.0);
+
+        Point3D::new(r * u.cos(), v / 2.0 * f32::sin(u / 2.0), r * u.sin())
+    })
+    .with_u(0.0..TAU, 48, Wrap::Flipped)
+    .with_v(-1.0..1.0, 6, Wrap::Open)
+    .build()
+}
+
+// The figure-8 immersion, a Klein bottle can't be embedded in 3D without passing through itself
+pub fn klein_bottle() -> Mesh {
+    Surface::new("klein_bottle", |u, v| {
+        let (sin_half, cos_half) = (u / 2.0).sin_cos();
+        let r = 2.0 + cos_half * v.sin() - sin_half * f32::sin(2.0 * v);
+
+        Point3D::new(
+            r * u.cos(),
+            sin_half * v.sin() + cos_half * f32::sin(2.0 * v),
+            r * u.sin(),
+        )
+    })
+    .with_u(0.0..TAU, 48, Wrap::Flipped)
+    .with_v(0.0..TAU, 24, Wrap::Closed)
+    .build()
+}
+
+// Winds `p` times around the torus' axis and `q` times through its hole, (2, 3) is the trefoil
+pub fn torus_knot(p: u32, q: u32) -> Mesh {
+    let (p, q) = (p as f32, q as f32);
+
+    Curve::new("torus_knot", |t| {
+        let r = 2.0 + f32::cos(q * t);
+
+        Point3D::new(r * f32::cos(p * t), -f32::sin(q * t), r * f32::sin(p * t))
+    })
+    .with_t(0.0..TAU, 256, Wrap::Closed)
+    .build()
+}
@@ -0,0 +1,76 @@
+use super::Shape;
+use super::polyhedron::{self, PHI};
+use crate::point3d::Point3D;
+
+pub struct Tetrahedron;
+pub struct Hexahedron;
+pub struct Octahedron;
+pub struct Dodecahedron;
+pub struct Icosahedron;
+
+impl Shape for Tetrahedron {
+    fn name() -> &'static str {
+        "tetrahedron"
+    }
+
+    // Alternate corners of the cube, the ones with an even number of negative coordinates
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 1.0, 1.0]])
+            .into_iter()
+            .filter(|v| v.x * v.y * v.z > 0.0)
+            .collect()
+    }
+
+    polyhedron::hull_indices!();
+}
+
+// Same faces as cube::Cube at twice the size, found from the hull like the other solids
+impl Shape for Hexahedron {
+    fn name() -> &'static str {
+        "hexahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 1.0, 1.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Octahedron {
+    fn name() -> &'static str {
+        "octahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::permutations(&[[1.0, 0.0, 0.0]])
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Dodecahedron {
+    fn name() -> &'static str {
+        "dodecahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        let mut vertices = polyhedron::permutations(&[[1.0, 1.0, 1.0]]);
+        vertices.extend(polyhedron::cyclic_permutations(&[[0.0, 1.0 / PHI, PHI]]));
+        vertices
+    }
+
+    polyhedron::hull_indices!();
+}
+
+impl Shape for Icosahedron {
+    fn name() -> &'static str {
+        "icosahedron"
+    }
+
+    fn vertices() -> Vec<Point3D> {
+        polyhedron::cyclic_permutations(&[[0.0, 1.0, PHI]])
+    }
+
+    polyhedron::hull_indices!();
+}
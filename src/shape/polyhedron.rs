@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::point3d::Point3D;
+
+// Golden ratio, most of the icosahedral solids are built from it
+pub const PHI: f32 = 1.618_034;
+
+// Every sign combination of every coordinate in every order, duplicates (from zeros or repeated values) removed
+pub fn permutations(points: &[[f32; 3]]) -> Vec<Point3D> {
+    expand(
+        points,
+        &[
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ],
+    )
+}
+
+// Like permutations but only rotating the coordinates, which is what the icosahedral solids need
+pub fn cyclic_permutations(points: &[[f32; 3]]) -> Vec<Point3D> {
+    expand(points, &[[0, 1, 2], [1, 2, 0], [2, 0, 1]])
+}
+
+fn expand(points: &[[f32; 3]], orders: &[[usize; 3]]) -> Vec<Point3D> {
+    let mut vertices: Vec<Point3D> = Vec::new();
+
+    for point in points {
+        for order in orders {
+            for signs in 0..8 {
+                let sign = |axis: usize| match signs & (1 << axis) {
+                    0 => 1.0,
+                    _ => -1.0,
+                };
+                let vertex = Point3D::new(
+                    sign(0) * point[order[0]],
+                    sign(1) * point[order[1]],
+                    sign(2) * point[order[2]],
+                );
+
+                if !vertices.iter().any(|v| v.approx_eq(&vertex, 1e-5)) {
+                    vertices.push(vertex);
+                }
+            }
+        }
+    }
+
+    vertices
+}
+
+// Faces of the convex hull of the vertices, coplanar vertices are merged into one polygon instead of being triangulated
+// Each face is wound counter-clockwise when seen from outside, matching the generated primitives
+pub fn faces(vertices: &[Point3D]) -> Vec<Vec<usize>> {
+    let scale = vertices.iter().map(Point3D::length).fold(0.0, f32::max);
+    let epsilon = scale * 1e-4;
+    let centroid =
+        vertices.iter().fold(Point3D::default(), |sum, v| sum + *v) / vertices.len() as f32;
+
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    let mut faces = Vec::new();
+
+    for i in 0..vertices.len() {
+        for j in i + 1..vertices.len() {
+            for k in j + 1..vertices.len() {
+                let normal = (vertices[j] - vertices[i]).cross(&(vertices[k] - vertices[i]));
+
+                if normal.length() <= epsilon * epsilon {
+                    continue;
+                }
+
+                // Point the normal away from the middle of the solid so a supporting plane has everything behind it
+                let mut normal = normal.normalize();
+
+                if normal.dot(&(vertices[i] - centroid)) < 0.0 {
+                    normal = -normal;
+                }
+
+                let distance = normal.dot(&vertices[i]);
+
+                if vertices.iter().any(|v| normal.dot(v) > distance + epsilon) {
+                    continue;
+                }
+
+                let face: Vec<usize> = (0..vertices.len())
+                    .filter(|&v| (normal.dot(&vertices[v]) - distance).abs() <= epsilon)
+                    .collect();
+
+                if seen.insert(face.clone()) {
+                    faces.push(wind(vertices, face, &normal));
+                }
+            }
+        }
+    }
+
+    faces
+}
+
+// The hull search is brute force, so each solid keeps its faces in a static and only runs it the first time
+pub fn cached_faces(
+    cell: &OnceLock<Vec<Vec<usize>>>,
+    vertices: fn() -> Vec<Point3D>,
+) -> Vec<Vec<usize>> {
+    cell.get_or_init(|| faces(&vertices())).clone()
+}
+
+// The `indices` of a Shape whose faces are the hull of its `vertices`, with its own static for cached_faces
+macro_rules! hull_indices {
+    () => {
+        fn indices() -> Vec<Vec<usize>> {
+            static FACES: std::sync::OnceLock<Vec<Vec<usize>>> = std::sync::OnceLock::new();
+            $crate::shape::polyhedron::cached_faces(&FACES, Self::vertices)
+        }
+    };
+}
+
+pub(crate) use hull_indices;
+
+// Sorts the corners by angle around the face's center
+fn wind(vertices: &[Point3D], mut face: Vec<usize>, normal: &Point3D) -> Vec<usize> {
+    let center = face
+        .iter()
+        .fold(Point3D::default(), |sum, &v| sum + vertices[v])
+        / face.len() as f32;
+    let u = (vertices[face[0]] - center).normalize();
+    let w = normal.cross(&u);
+
+    let angle = |v: &usize| {
+        let d = vertices[*v] - center;
+
+        f32::atan2(d.dot(&w), d.dot(&u))
+    };
+
+    face.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+    face
+}
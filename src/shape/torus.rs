@@ -0,0 +1,65 @@
+use std::f32::consts::PI;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Ring around the y axis, `segments` steps around the ring and `sides` steps around the tube
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Torus {
+    pub major_radius: f32,
+    pub minor_radius: f32,
+    pub segments: usize,
+    pub sides: usize,
+}
+
+impl Default for Torus {
+    fn default() -> Self {
+        Self {
+            major_radius: 0.35,
+            minor_radius: 0.15,
+            segments: 32,
+            sides: 12,
+        }
+    }
+}
+
+impl Torus {
+    pub fn build(&self) -> Mesh {
+        let segments = self.segments.max(3);
+        let sides = self.sides.max(3);
+
+        let mut vertices = Vec::with_capacity(segments * sides);
+
+        for segment in 0..segments {
+            let (sin_theta, cos_theta) = (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+
+            for side in 0..sides {
+                let (sin_phi, cos_phi) = (2.0 * PI * side as f32 / sides as f32).sin_cos();
+                let r = self.major_radius + self.minor_radius * cos_phi;
+
+                vertices.push(Point3D::new(
+                    r * cos_theta,
+                    self.minor_radius * sin_phi,
+                    r * sin_theta,
+                ));
+            }
+        }
+
+        let at = |segment: usize, side: usize| (segment % segments) * sides + side % sides;
+
+        let mut faces = Vec::with_capacity(segments * sides);
+
+        for segment in 0..segments {
+            for side in 0..sides {
+                faces.push(vec![
+                    at(segment, side),
+                    at(segment, side + 1),
+                    at(segment + 1, side + 1),
+                    at(segment + 1, side),
+                ]);
+            }
+        }
+
+        Mesh::new("torus", vertices, faces)
+    }
+}
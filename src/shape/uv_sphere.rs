@@ -0,0 +1,79 @@
+use std::f32::consts::PI;
+
+use crate::mesh::Mesh;
+use crate::point3d::Point3D;
+
+// Sphere around the y axis made of `segments` meridians and `rings` bands, the poles are single vertices
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvSphere {
+    pub radius: f32,
+    pub segments: usize,
+    pub rings: usize,
+}
+
+impl Default for UvSphere {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            segments: 24,
+            rings: 12,
+        }
+    }
+}
+
+impl UvSphere {
+    pub fn build(&self) -> Mesh {
+        let segments = self.segments.max(3);
+        let rings = self.rings.max(2);
+
+        let mut vertices = vec![Point3D::new(0.0, self.radius, 0.0)];
+
+        for ring in 1..rings {
+            let (sin_phi, cos_phi) = (PI * ring as f32 / rings as f32).sin_cos();
+
+            for segment in 0..segments {
+                let (sin_theta, cos_theta) =
+                    (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+
+                vertices.push(Point3D::new(
+                    self.radius * sin_phi * cos_theta,
+                    self.radius * cos_phi,
+                    self.radius * sin_phi * sin_theta,
+                ));
+            }
+        }
+
+        vertices.push(Point3D::new(0.0, -self.radius, 0.0));
+
+        let top = 0;
+        let bottom = vertices.len() - 1;
+        let at = |ring: usize, segment: usize| 1 + (ring - 1) * segments + segment % segments;
+
+        let mut faces = Vec::new();
+
+        for segment in 0..segments {
+            faces.push(vec![top, at(1, segment + 1), at(1, segment)]);
+        }
+
+        for ring in 1..rings - 1 {
+            for segment in 0..segments {
+                faces.push(vec![
+                    at(ring, segment),
+                    at(ring, segment + 1),
+                    at(ring + 1, segment + 1),
+                    at(ring + 1, segment),
+                ]);
+            }
+        }
+
+        for segment in 0..segments {
+            faces.push(vec![
+                bottom,
+                at(rings - 1, segment),
+                at(rings - 1, segment + 1),
+            ]);
+        }
+
+        Mesh::new("uv_sphere", vertices, faces)
+    }
+}
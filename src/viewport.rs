@@ -0,0 +1,53 @@
+use crate::constants::{CANVAS_HEIGHT, CANVAS_WIDTH};
+use crate::point2d::Point2D;
+
+// Region of the canvas that normalized device coordinates are mapped onto, sizes are in logical (not physical) pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    pub width: f32,
+    pub height: f32,
+    pub offset: Point2D,
+    pub pixel_ratio: f32,
+}
+
+impl Viewport {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            offset: Point2D::new(0.0, 0.0),
+            pixel_ratio: 1.0,
+        }
+    }
+
+    pub fn aspect(&self) -> f32 {
+        self.width / self.height
+    }
+
+    // Maps [-1, 1] on both axes onto the viewport, the camera's aspect ratio keeps this from stretching the model
+    pub fn to_screen(&self, ndc: &Point2D) -> Point2D {
+        Point2D::new(
+            self.offset.x + ndc.x * (self.width / 2.0),
+            self.offset.y + ndc.y * (self.height / 2.0),
+        )
+    }
+
+    // Window sizes arrive in physical pixels
+    pub fn resize(&mut self, width: f32, height: f32) {
+        self.width = width / self.pixel_ratio;
+        self.height = height / self.pixel_ratio;
+    }
+
+    // Keeps the physical size the same, only the logical size changes
+    pub fn set_pixel_ratio(&mut self, pixel_ratio: f32) {
+        self.width *= self.pixel_ratio / pixel_ratio;
+        self.height *= self.pixel_ratio / pixel_ratio;
+        self.pixel_ratio = pixel_ratio;
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new(CANVAS_WIDTH, CANVAS_HEIGHT)
+    }
+}